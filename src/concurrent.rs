@@ -0,0 +1,215 @@
+use crate::lab::ListError;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Thread-safe equivalent of `lab::Link`.
+///
+/// Shorthand for an Atomically Reference Counted pointer (`Arc`) holding a `Mutex` which makes sure
+/// only one thread at a time can access the stored `Node<T>`.
+pub type Link<T> = Option<Arc<Mutex<Node<T>>>>;
+
+/// A node in a `ConcurrentList`.
+///
+/// Each node will store data and links to the next and previous nodes. The sentinel's data is
+/// always `None`.
+#[derive(Debug)]
+pub struct Node<T: PartialEq + Clone> {
+    pub data: Option<T>,
+    pub next: Link<T>,
+    pub prev: Link<T>,
+}
+
+/// A circular linked list with a sentinel node that can be shared between threads.
+///
+/// ## Locking order
+///
+/// Every operation locks the sentinel first and holds that lock until it is done, so the sentinel
+/// doubles as a lock on the whole list and operations never run at the same time. While the
+/// sentinel is held, at most one other node is locked at a time. Because no thread ever waits on
+/// the sentinel while holding another node's lock, splices can't deadlock.
+pub struct ConcurrentList<T: PartialEq + Clone> {
+    size: AtomicUsize,
+    sentinel: Arc<Mutex<Node<T>>>,
+}
+
+impl<T: PartialEq + Clone> ConcurrentList<T> {
+    /// Constructs a new list with a size of 0.
+    pub fn new_list() -> Self {
+        let sentinel = Arc::new(Mutex::new(Node {
+            data: None,
+            prev: None,
+            next: None,
+        }));
+
+        {
+            let mut guard = sentinel.lock().unwrap();
+            guard.next = Some(Arc::clone(&sentinel));
+            guard.prev = Some(Arc::clone(&sentinel));
+        }
+
+        Self {
+            size: AtomicUsize::new(0),
+            sentinel,
+        }
+    }
+
+    /// Determines if the list is empty.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `true` if the list is empty.
+    /// 2. `false` if the list isn't empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the number of elements in the list, not counting the sentinel.
+    ///
+    /// ## Returns
+    ///
+    /// The size of the list.
+    pub fn len(&self) -> usize {
+        self.size.load(Ordering::SeqCst)
+    }
+
+    /// Adds data to the front of the list.
+    ///
+    /// ## Parameters
+    ///
+    /// - `data: T` is the data to add.
+    pub fn add(&self, data: T) {
+        let mut sentinel = self.sentinel.lock().unwrap();
+        let head = sentinel.next.clone().unwrap();
+
+        let new_node = Arc::new(Mutex::new(Node {
+            data: Some(data),
+            prev: Some(Arc::clone(&self.sentinel)),
+            next: Some(Arc::clone(&head)),
+        }));
+
+        // The sentinel is already locked, so it has to be updated through the guard rather than
+        // locked a second time
+        if Arc::ptr_eq(&head, &self.sentinel) {
+            sentinel.prev = Some(Arc::clone(&new_node));
+        } else {
+            head.lock().unwrap().prev = Some(Arc::clone(&new_node));
+        }
+        sentinel.next = Some(new_node);
+
+        self.size.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Removes the data at the specified index. If index is invalid then this function does
+    /// nothing and returns an error.
+    ///
+    /// ## Parameters
+    ///
+    /// - `index: usize` is the index of the data to remove.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Ok(data)` where `data` is the data that was removed.
+    /// 2. `Err(ListError::Empty)` if the list is empty.
+    /// 3. `Err(ListError::IndexOutOfBounds)` if `index` is past the end of the list.
+    pub fn remove_index(&self, index: usize) -> Result<T, ListError> {
+        let mut sentinel = self.sentinel.lock().unwrap();
+        let size = self.len();
+
+        if size == 0 {
+            return Err(ListError::Empty);
+        } else if index >= size {
+            return Err(ListError::IndexOutOfBounds);
+        }
+
+        let mut current = sentinel.next.clone().unwrap();
+        for _ in 0..index {
+            let next = current.lock().unwrap().next.clone().unwrap();
+            current = next;
+        }
+
+        let (prev, next, data) = {
+            let mut node = current.lock().unwrap();
+            (
+                node.prev.take().unwrap(),
+                node.next.take().unwrap(),
+                node.data.take().unwrap(),
+            )
+        };
+
+        self.with_node(&mut sentinel, &prev, |node| {
+            node.next = Some(Arc::clone(&next))
+        });
+        self.with_node(&mut sentinel, &next, |node| {
+            node.prev = Some(Arc::clone(&prev))
+        });
+
+        self.size.fetch_sub(1, Ordering::SeqCst);
+
+        Ok(data)
+    }
+
+    /// Search for any occurrence of `data` from the list. If there are multiple copies of the same
+    /// data in the list, the first one will be returned.
+    ///
+    /// ## Parameters
+    ///
+    /// - `data: T` is the data to search for.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(index)` where `index` is the location of the target data.
+    /// 2. `None` if the target data couldn't be found.
+    pub fn index_of(&self, data: T) -> Option<usize> {
+        // Hold the sentinel so nothing can be spliced while we walk
+        let sentinel = self.sentinel.lock().unwrap();
+        let mut current = sentinel.next.clone().unwrap();
+
+        for index in 0..self.len() {
+            let next = {
+                let node = current.lock().unwrap();
+                if node.data.as_ref() == Some(&data) {
+                    return Some(index);
+                }
+                node.next.clone().unwrap()
+            };
+            current = next;
+        }
+
+        None
+    }
+
+    /// Runs `f` on `node`, going through the sentinel's guard when `node` is the sentinel since it
+    /// is already locked.
+    fn with_node<F: FnOnce(&mut Node<T>)>(
+        &self,
+        sentinel: &mut MutexGuard<'_, Node<T>>,
+        node: &Arc<Mutex<Node<T>>>,
+        f: F,
+    ) {
+        if Arc::ptr_eq(node, &self.sentinel) {
+            f(sentinel);
+        } else {
+            f(&mut node.lock().unwrap());
+        }
+    }
+}
+
+// Break the ring so the nodes are actually freed
+impl<T: PartialEq + Clone> Drop for ConcurrentList<T> {
+    fn drop(&mut self) {
+        let mut sentinel = self.sentinel.lock().unwrap();
+        let mut current = sentinel.next.take();
+
+        while let Some(node) = current {
+            if Arc::ptr_eq(&node, &self.sentinel) {
+                break;
+            }
+
+            let mut guard = node.lock().unwrap();
+            guard.prev = None;
+            current = guard.next.take();
+        }
+
+        sentinel.prev = None;
+    }
+}
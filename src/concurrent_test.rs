@@ -0,0 +1,81 @@
+use crate::concurrent::ConcurrentList;
+use crate::lab::ListError;
+use std::sync::Arc;
+use std::thread;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_is_send_and_sync() {
+    assert_send_sync::<ConcurrentList<usize>>();
+}
+
+#[test]
+fn test_create() {
+    let list: ConcurrentList<usize> = ConcurrentList::new_list();
+
+    assert_eq!(list.len(), 0);
+    assert!(list.is_empty());
+    assert_eq!(list.index_of(0), None);
+}
+
+#[test]
+fn test_add_and_index_of() {
+    let list: ConcurrentList<usize> = ConcurrentList::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    // List should be 4 -> 3 -> 2 -> 1 -> 0 -> (sentinel)
+
+    assert_eq!(list.len(), 5);
+    assert_eq!(list.index_of(4), Some(0));
+    assert_eq!(list.index_of(0), Some(4));
+    assert_eq!(list.index_of(22), None);
+}
+
+#[test]
+fn test_remove_index() {
+    let list: ConcurrentList<usize> = ConcurrentList::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    assert_eq!(list.remove_index(3), Ok(1));
+    assert_eq!(list.remove_index(3), Ok(0));
+    assert_eq!(list.remove_index(0), Ok(4));
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.index_of(3), Some(0));
+    assert_eq!(list.index_of(2), Some(1));
+    assert_eq!(list.remove_index(2), Err(ListError::IndexOutOfBounds));
+
+    assert_eq!(list.remove_index(0), Ok(3));
+    assert_eq!(list.remove_index(0), Ok(2));
+    assert_eq!(list.remove_index(0), Err(ListError::Empty));
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_add_from_many_threads() {
+    let list: Arc<ConcurrentList<usize>> = Arc::new(ConcurrentList::new_list());
+
+    let handles: Vec<_> = (0..8)
+        .map(|t| {
+            let list = Arc::clone(&list);
+            thread::spawn(move || {
+                for i in 0..100 {
+                    list.add(t * 100 + i);
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(list.len(), 800);
+    for value in [0, 150, 799] {
+        assert!(list.index_of(value).is_some());
+    }
+}
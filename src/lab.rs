@@ -1,6 +1,12 @@
-use std::cell::RefCell;
-use std::fmt::Display;
-use std::rc::Rc;
+use alloc::collections::{LinkedList, VecDeque};
+use alloc::rc::{Rc, Weak};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display};
+use core::hash::{Hash, Hasher};
+use core::iter::{Enumerate, Product, Sum};
 
 /// Custom type to make the `Node` struct more readable.
 ///
@@ -9,36 +15,108 @@ use std::rc::Rc;
 /// `Node<T>`.
 pub type Link<T> = Option<Rc<RefCell<Node<T>>>>;
 
+/// Non-owning counterpart to `Link<T>`, used for the backward links.
+///
+/// A `Weak` pointer doesn't keep the `Node<T>` alive, so following one means calling `upgrade` to
+/// get a `Link<T>` back. `Node::prev_node` does this.
+pub type WeakLink<T> = Option<Weak<RefCell<Node<T>>>>;
+
+/// Errors that can be returned by the fallible list operations.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ListError {
+    /// The list has no elements to operate on.
+    Empty,
+    /// The index given is past the end of the list.
+    IndexOutOfBounds,
+    /// The data being searched for isn't in the list.
+    NotFound,
+    /// The start of the range given is after its end.
+    InvalidRange,
+    /// The same index was given more than once.
+    DuplicateIndex,
+    /// The links or size of the list are inconsistent. Holds a description of what is wrong.
+    Corrupt(&'static str),
+}
+
+impl Display for ListError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ListError::Empty => write!(f, "The list is empty, nothing was done"),
+            ListError::IndexOutOfBounds => write!(f, "Index out of bounds"),
+            ListError::NotFound => write!(f, "The data couldn't be found, nothing was done"),
+            ListError::InvalidRange => write!(f, "The range starts after it ends"),
+            ListError::DuplicateIndex => write!(f, "An index was given more than once"),
+            ListError::Corrupt(reason) => write!(f, "The list is corrupt: {}", reason),
+        }
+    }
+}
+
+impl core::error::Error for ListError {}
+
 /// A node in the list.
 ///
-/// Each node will store data and links to the next and previous nodes.
-#[derive(PartialEq, Debug)]
-pub struct Node<T: PartialEq + Default + Display + Clone> {
-    pub data: T,
+/// Each node will store data and links to the next and previous nodes. Only the link to the next
+/// node owns it, the link to the previous node is weak. The sentinel's data is always `None`.
+#[derive(Debug)]
+pub struct Node<T: PartialEq + Clone> {
+    pub data: Option<T>,
     pub next: Link<T>,
-    pub prev: Link<T>,
+    pub prev: WeakLink<T>,
+}
+
+impl<T: PartialEq + Clone> Node<T> {
+    /// Follows the weak `prev` pointer.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(node)` where `node` is a strong pointer to the previous node.
+    /// 2. `None` if there is no previous node or it has already been freed.
+    pub fn prev_node(&self) -> Link<T> {
+        self.prev.as_ref().and_then(Weak::upgrade)
+    }
+
+    /// Borrows the data stored in a node that isn't the sentinel.
+    fn value(&self) -> &T {
+        self.data.as_ref().expect("the sentinel holds no data")
+    }
+
+    /// Mutably borrows the data stored in a node that isn't the sentinel.
+    fn value_mut(&mut self) -> &mut T {
+        self.data.as_mut().expect("the sentinel holds no data")
+    }
 }
 
-/// Struct to represent a list. The list maintains 1 function pointer to help with the management
-/// of the data it is storing. This function must be provided by the user of this library.
-#[derive(PartialEq, Debug)]
-pub struct List<T: PartialEq + Default + Display + Clone> {
-    pub size: usize,
+/// Struct to represent a list. The list maintains 1 optional function pointer to help with the
+/// management of the data it is storing. This function must be provided by the user of this
+/// library through `new_list_with_cmp`, otherwise `PartialEq` and `Ord` are used.
+///
+/// There's no `list[i]` indexing since the data lives behind a `RefCell` and a plain `&T` into it
+/// couldn't be kept from dangling, so use `get` to read a clone of the data at an index instead.
+///
+/// ```compile_fail
+/// use sentinel_circular_linked_list::lab::List;
+///
+/// let list = List::from(vec![1, 2, 3]);
+/// let first = &list[0];
+/// ```
+pub struct List<T: PartialEq + Clone> {
+    pub(crate) size: usize,
     pub sentinel: Rc<RefCell<Node<T>>>,
+    compare: Option<fn(&T, &T) -> Ordering>,
 }
 
-impl<T: PartialEq + Default + Display + Clone> List<T> {
+impl<T: PartialEq + Clone> List<T> {
     /// Constructs a new list with a size of 0.
     ///
     /// ## Returns
     ///
     /// The properly typed equivalent of the following:
     ///
-    /// ```rust
+    /// ```ignore
     /// List {
-    ///     data: T::default(),
+    ///     size: 0,
     ///     sentinel: Node {
-    ///         data: T::default(),
+    ///         data: None,
     ///         prev: &sentinel,
     ///         next: &sentinel,
     ///     },
@@ -46,15 +124,92 @@ impl<T: PartialEq + Default + Display + Clone> List<T> {
     /// ```
     pub fn new_list() -> Self {
         let sentinel = Rc::new(RefCell::new(Node {
-            data: T::default(),
+            data: None,
             prev: None,
             next: None,
         }));
 
         sentinel.borrow_mut().next = Some(Rc::clone(&sentinel));
-        sentinel.borrow_mut().prev = Some(Rc::clone(&sentinel));
+        sentinel.borrow_mut().prev = Some(Rc::downgrade(&sentinel));
+
+        Self {
+            size: 0,
+            sentinel,
+            compare: None,
+        }
+    }
+
+    /// Constructs a new list with a size of 0 that uses `cmp` to compare its data. Searching
+    /// methods like `index_of`, `contains`, `count`, `remove_all`, and `dedup` treat elements as
+    /// equal when `cmp` returns `Ordering::Equal`, and `sort` orders by `cmp`, instead of using
+    /// `PartialEq` and `Ord`. This allows for things like case-insensitive lists of strings.
+    /// Comparing two whole lists with `==` still uses `PartialEq` so that it agrees with `Hash`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `cmp: fn(&T, &T) -> Ordering` is the function used to compare two elements.
+    pub fn new_list_with_cmp(cmp: fn(&T, &T) -> Ordering) -> Self {
+        let mut list = Self::new_list();
+        list.compare = Some(cmp);
+        list
+    }
+
+    /// Constructs a new, empty list that uses the same comparator as this one.
+    fn new_like(&self) -> Self {
+        let mut list = Self::new_list();
+        list.compare = self.compare;
+        list
+    }
 
-        Self { size: 0, sentinel }
+    /// Constructs a new list holding a clone of each element of a slice, in the same order.
+    ///
+    /// ## Parameters
+    ///
+    /// - `items: &[T]` is the data to fill the list with.
+    pub fn with_elements(items: &[T]) -> Self {
+        items.iter().cloned().collect()
+    }
+
+    /// Constructs a new list by adding each item to the front with `add`, so the list ends up in
+    /// the reverse of the iterator's order. `0..3` gives `2 -> 1 -> 0`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `iter: I` is the data to fill the list with.
+    pub fn from_iter_front<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new_list();
+        for data in iter {
+            list.add(data);
+        }
+
+        list
+    }
+
+    /// Constructs a new list by adding each item to the back with `push_back`, so the list ends up
+    /// in the same order as the iterator. `0..3` gives `0 -> 1 -> 2`. This is what `collect` does.
+    ///
+    /// ## Parameters
+    ///
+    /// - `iter: I` is the data to fill the list with.
+    pub fn from_iter_back<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new_list();
+        list.extend(iter);
+        list
+    }
+
+    /// Constructs a new list holding `count` clones of `item`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `item: T` is the data to fill the list with.
+    /// - `count: usize` is the number of copies to add.
+    pub fn repeat(item: T, count: usize) -> Self {
+        let mut list = Self::new_list();
+        for _ in 0..count {
+            list.push_back(item.clone());
+        }
+
+        list
     }
 
     /// Determines if the list is empty.
@@ -67,6 +222,75 @@ impl<T: PartialEq + Default + Display + Clone> List<T> {
         self.size == 0
     }
 
+    /// Gets the number of elements in the list, not counting the sentinel.
+    ///
+    /// ## Returns
+    ///
+    /// The size of the list.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Checks that the list is well-formed. Walking the `next` links from the sentinel has to reach
+    /// exactly `size` nodes before coming back around to the sentinel, each node's `prev` has to
+    /// point at the node before it, and only the sentinel can be without data.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Ok(())` if the list is well-formed.
+    /// 2. `Err(ListError::Corrupt(reason))` where `reason` describes the first problem found.
+    pub fn validate(&self) -> Result<(), ListError> {
+        if self.sentinel.borrow().data.is_some() {
+            return Err(ListError::Corrupt("the sentinel holds data"));
+        }
+
+        let mut count: usize = 0;
+        let mut current = Rc::clone(&self.sentinel);
+
+        loop {
+            let next = current
+                .borrow()
+                .next
+                .clone()
+                .ok_or(ListError::Corrupt("a next link is missing"))?;
+
+            let points_back = next
+                .borrow()
+                .prev_node()
+                .is_some_and(|prev| Rc::ptr_eq(&prev, &current));
+            if !points_back {
+                return Err(ListError::Corrupt(
+                    "a prev link doesn't point at the node before it",
+                ));
+            }
+
+            if Rc::ptr_eq(&next, &self.sentinel) {
+                break;
+            }
+
+            // Stopping once we're past size also keeps a ring that skips the sentinel from
+            // looping forever
+            count += 1;
+            if count > self.size {
+                return Err(ListError::Corrupt("there are more nodes than size says"));
+            }
+
+            if next.borrow().data.is_none() {
+                return Err(ListError::Corrupt(
+                    "a node other than the sentinel has no data",
+                ));
+            }
+
+            current = next;
+        }
+
+        if count < self.size {
+            return Err(ListError::Corrupt("there are fewer nodes than size says"));
+        }
+
+        Ok(())
+    }
+
     /// Adds data to the front of the list.
     ///
     /// ## Parameters
@@ -75,8 +299,8 @@ impl<T: PartialEq + Default + Display + Clone> List<T> {
     pub fn add(&mut self, data: T) {
         // Put the input data into a Node
         let new_node = Rc::new(RefCell::new(Node {
-            data,
-            prev: Some(Rc::clone(&self.sentinel)),
+            data: Some(data),
+            prev: Some(Rc::downgrade(&self.sentinel)),
             next: self.sentinel.borrow().next.clone(),
         }));
 
@@ -94,7 +318,11 @@ impl<T: PartialEq + Default + Display + Clone> List<T> {
                     .clone()
                     .unwrap()
                     .borrow_mut()
-                    .prev = Some(Rc::clone(&new_node));
+                    .prev = Some(Rc::downgrade(&new_node));
+            } else {
+                // Otherwise the list was empty, so the new node is also the last node and the
+                // sentinel needs to point backwards to it
+                self.sentinel.borrow_mut().prev = Some(Rc::downgrade(&new_node));
             }
 
             // Removed unnecessary double sentinel.next update. Caught by Michael Olasci
@@ -106,122 +334,2116 @@ impl<T: PartialEq + Default + Display + Clone> List<T> {
         self.size += 1;
     }
 
-    /// Removes teh data at the specified index. If index is invalid then this function does
-    /// nothing and returns `None`.
+    /// Adds data to the back of the list.
     ///
     /// ## Parameters
     ///
-    /// - `index: usize` is the index of the data to remove.
+    /// - `data: T` is the data to add.
+    pub fn push_back(&mut self, data: T) {
+        // The current last node, which is the sentinel itself when the list is empty
+        let tail = self.sentinel.borrow().prev_node().unwrap();
+
+        // Put the input data into a Node that sits between the old tail and the sentinel
+        let new_node = Rc::new(RefCell::new(Node {
+            data: Some(data),
+            prev: Some(Rc::downgrade(&tail)),
+            next: Some(Rc::clone(&self.sentinel)),
+        }));
+
+        tail.borrow_mut().next = Some(Rc::clone(&new_node));
+        self.sentinel.borrow_mut().prev = Some(Rc::downgrade(&new_node));
+
+        self.size += 1;
+    }
+
+    /// Adds a clone of each element of a slice to the front of the list. Unlike calling `add` in a
+    /// loop, the slice keeps its order, so `items[0]` becomes the new front, followed by `items[1]`
+    /// and so on, with the old front of the list coming right after the last item.
+    ///
+    /// ## Parameters
+    ///
+    /// - `items: &[T]` is the data to add.
+    pub fn add_front_slice(&mut self, items: &[T]) {
+        // Adding back to front leaves the first item at the very front
+        for item in items.iter().rev() {
+            self.add(item.clone());
+        }
+    }
+
+    /// Inserts data so that it ends up at the specified index. An index of 0 adds the data to the
+    /// front of the list and an index equal to the size of the list adds it to the back.
+    ///
+    /// ## Parameters
+    ///
+    /// - `index: usize` is the index the data should end up at.
+    /// - `data: T` is the data to insert.
     ///
     /// ## Returns
     ///
-    /// 1. `Ok(())` if the target index was successfully removed.
-    /// 2. `Err(String)` if the list is empty or the target index couldn't be found.
-    pub fn remove_index(&mut self, index: usize) -> Result<T, String> {
-        if self.is_empty() {
-            Err(String::from("The list is empty, nothing was done"))
-        } else if index >= self.size {
-            Err(String::from("Index out of bounds"))
+    /// 1. `Ok(())` if the data was inserted.
+    /// 2. `Err(ListError::IndexOutOfBounds)` if `index` is greater than the size of the list.
+    pub fn insert(&mut self, index: usize, data: T) -> Result<(), ListError> {
+        if index > self.size {
+            Err(ListError::IndexOutOfBounds)
         } else {
-            let mut current = self.sentinel.borrow().next.clone();
-            let mut i = 0;
+            // Find the node that will end up right before the new one, starting from the sentinel
+            // so that an index of 0 inserts at the front
+            let mut before = Rc::clone(&self.sentinel);
+            for _ in 0..index {
+                let next = before.borrow().next.clone().unwrap();
+                before = next;
+            }
 
-            while let Some(node) = current {
-                // Changed from a pointer equivalency check to an index/size check under suggestion
-                // from Michael Olasci
-                if i == self.size {
-                    break;
-                }
+            self.insert_after_node(&before, data);
 
-                if i == index {
-                    let prev = node.borrow().prev.clone();
-                    let next = node.borrow().next.clone();
-                    let data = node.borrow().data.clone();
+            Ok(())
+        }
+    }
 
-                    if let Some(prev_node) = prev.clone() {
-                        prev_node.borrow_mut().next = next.clone();
-                    }
+    /// Inserts data directly after the first occurrence of `target`. If `target` is the last
+    /// element then the new data becomes the new last element.
+    ///
+    /// ## Parameters
+    ///
+    /// - `target: &T` is the data to insert after.
+    /// - `data: T` is the data to insert.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Ok(())` if the data was inserted.
+    /// 2. `Err(ListError::NotFound)` if `target` isn't in the list.
+    pub fn insert_after_value(&mut self, target: &T, data: T) -> Result<(), ListError> {
+        let node = self.find_node(target).ok_or(ListError::NotFound)?;
+        self.insert_after_node(&node, data);
+        Ok(())
+    }
 
-                    if let Some(next_node) = next {
-                        next_node.borrow_mut().prev = prev.clone();
-                    }
+    /// Inserts data directly before the first occurrence of `target`. If `target` is the first
+    /// element then the new data becomes the new first element.
+    ///
+    /// ## Parameters
+    ///
+    /// - `target: &T` is the data to insert before.
+    /// - `data: T` is the data to insert.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Ok(())` if the data was inserted.
+    /// 2. `Err(ListError::NotFound)` if `target` isn't in the list.
+    pub fn insert_before_value(&mut self, target: &T, data: T) -> Result<(), ListError> {
+        let node = self.find_node(target).ok_or(ListError::NotFound)?;
 
-                    self.size -= 1;
+        // Inserting after the node before target, which is the sentinel when target is the head
+        let before = node.borrow().prev_node().unwrap();
+        self.insert_after_node(&before, data);
 
-                    return Ok(data);
-                }
+        Ok(())
+    }
 
-                current = node.borrow().next.clone();
-                i += 1;
+    /// Finds the first node holding data equal to `target`, never returning the sentinel.
+    fn find_node(&self, target: &T) -> Link<T> {
+        let mut current = self.sentinel.borrow().next.clone().unwrap();
+
+        while !Rc::ptr_eq(&current, &self.sentinel) {
+            if self.data_eq(current.borrow().value(), target) {
+                return Some(current);
             }
 
-            Err(String::from(
-                "The index couldn't be found, nothing was done.",
-            ))
+            let next = current.borrow().next.clone().unwrap();
+            current = next;
         }
+
+        None
     }
 
-    /// Search for any occurrence of `data` from the list. Internally, this function will call
-    /// `compare_to` on each item in the list until a match is found or the end of the list is
-    /// reached. If there are multiple copies of the same data in the list, the first one will be
-    /// returned.
+    /// Splices a new node holding `data` in directly after `node`, which may be the sentinel.
+    fn insert_after_node(&mut self, node: &Rc<RefCell<Node<T>>>, data: T) {
+        let after = node.borrow().next.clone().unwrap();
+
+        let new_node = Rc::new(RefCell::new(Node {
+            data: Some(data),
+            prev: Some(Rc::downgrade(node)),
+            next: Some(Rc::clone(&after)),
+        }));
+
+        node.borrow_mut().next = Some(Rc::clone(&new_node));
+        after.borrow_mut().prev = Some(Rc::downgrade(&new_node));
+
+        self.size += 1;
+    }
+
+    /// Moves all of the elements of `other` onto the back of this list, leaving `other` empty. This
+    /// only relinks the ends of the two rings, so it takes constant time.
     ///
     /// ## Parameters
     ///
-    /// - `data: usize` is the data to search for.
+    /// - `other: &mut List<T>` is the list to move the elements out of.
+    pub fn append(&mut self, other: &mut List<T>) {
+        if other.is_empty() {
+            return;
+        }
+
+        let tail = self.sentinel.borrow().prev_node().unwrap();
+        let other_head = other.sentinel.borrow().next.clone().unwrap();
+        let other_tail = other.sentinel.borrow().prev_node().unwrap();
+
+        // Hook the front of other onto our tail and the back of other onto our sentinel
+        tail.borrow_mut().next = Some(Rc::clone(&other_head));
+        other_head.borrow_mut().prev = Some(Rc::downgrade(&tail));
+        other_tail.borrow_mut().next = Some(Rc::clone(&self.sentinel));
+        self.sentinel.borrow_mut().prev = Some(Rc::downgrade(&other_tail));
+        self.size += other.size;
+
+        // Other no longer owns any nodes, so reset it to the empty ring
+        other.sentinel.borrow_mut().next = Some(Rc::clone(&other.sentinel));
+        other.sentinel.borrow_mut().prev = Some(Rc::downgrade(&other.sentinel));
+        other.size = 0;
+    }
+
+    /// Moves all of the elements of `other` onto the front of this list, leaving `other` empty.
+    /// The elements of `other` keep their order and come before the elements already in this list.
+    /// This only relinks the ends of the two rings, so it takes constant time.
+    ///
+    /// ## Parameters
+    ///
+    /// - `other: &mut List<T>` is the list to move the elements out of.
+    pub fn prepend(&mut self, other: &mut List<T>) {
+        if other.is_empty() {
+            return;
+        }
+
+        let head = self.sentinel.borrow().next.clone().unwrap();
+        let other_head = other.sentinel.borrow().next.clone().unwrap();
+        let other_tail = other.sentinel.borrow().prev_node().unwrap();
+
+        // Hook the back of other onto our head and the front of other onto our sentinel
+        other_tail.borrow_mut().next = Some(Rc::clone(&head));
+        head.borrow_mut().prev = Some(Rc::downgrade(&other_tail));
+        other_head.borrow_mut().prev = Some(Rc::downgrade(&self.sentinel));
+        self.sentinel.borrow_mut().next = Some(Rc::clone(&other_head));
+        self.size += other.size;
+
+        // Other no longer owns any nodes, so reset it to the empty ring
+        other.sentinel.borrow_mut().next = Some(Rc::clone(&other.sentinel));
+        other.sentinel.borrow_mut().prev = Some(Rc::downgrade(&other.sentinel));
+        other.size = 0;
+    }
+
+    /// Joins many lists into one, in order, by appending each of them onto the back of a new list.
+    /// No data is copied since each list's nodes are spliced in with `append`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `lists: Vec<List<T>>` is the lists to join, which are consumed.
     ///
     /// ## Returns
     ///
-    /// 1. `Some(index)` where `index` is the location of the target data.
-    /// 2. `None` if the target data couldn't be found.
-    pub fn index_of(&self, data: T) -> Option<usize> {
+    /// A list holding every element of `lists`, with the elements of each list kept together.
+    pub fn concat(lists: Vec<List<T>>) -> List<T> {
+        let mut combined = Self::new_list();
+        for mut list in lists {
+            combined.append(&mut list);
+        }
+
+        combined
+    }
+
+    /// Rotates the list to the left so that the element at index `n % size` becomes the front.
+    /// Only the sentinel is relinked, none of the data is moved.
+    ///
+    /// ## Parameters
+    ///
+    /// - `n: usize` is the number of positions to rotate by.
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.is_empty() || n.is_multiple_of(self.size) {
+            return;
+        }
+
+        let new_head = self.node_at(n % self.size).unwrap();
+        self.move_sentinel_before(&new_head);
+    }
+
+    /// Rotates the list to the right so that the last `n % size` elements are moved to the front.
+    /// Only the sentinel is relinked, none of the data is moved.
+    ///
+    /// ## Parameters
+    ///
+    /// - `n: usize` is the number of positions to rotate by.
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.is_empty() || n.is_multiple_of(self.size) {
+            return;
+        }
+
+        let new_head = self.node_at(self.size - n % self.size).unwrap();
+        self.move_sentinel_before(&new_head);
+    }
+
+    /// Moves each element's data one node towards the front, with the front element wrapping
+    /// around to the back. Unlike `rotate_left`, none of the nodes are relinked, so handles to
+    /// nodes from `get_node_ref` stay at the same index but see different data.
+    pub fn shift_left(&mut self) {
+        if self.size < 2 {
+            return;
+        }
+
+        // Walking back to front, each node hands its data to the node before it
+        let mut carry = None;
+        let mut current = self.sentinel.borrow().prev_node().unwrap();
+        while !Rc::ptr_eq(&current, &self.sentinel) {
+            core::mem::swap(&mut current.borrow_mut().data, &mut carry);
+
+            let prev = current.borrow().prev_node().unwrap();
+            current = prev;
+        }
+
+        // carry now holds what was at the front, and the tail is waiting for it
+        let tail = self.sentinel.borrow().prev_node().unwrap();
+        tail.borrow_mut().data = carry;
+    }
+
+    /// Moves each element's data one node towards the back, with the back element wrapping around
+    /// to the front. Unlike `rotate_right`, none of the nodes are relinked, so handles to nodes
+    /// from `get_node_ref` stay at the same index but see different data.
+    pub fn shift_right(&mut self) {
+        if self.size < 2 {
+            return;
+        }
+
+        // Walking front to back, each node hands its data to the node after it
+        let mut carry = None;
+        let mut current = self.sentinel.borrow().next.clone().unwrap();
+        while !Rc::ptr_eq(&current, &self.sentinel) {
+            core::mem::swap(&mut current.borrow_mut().data, &mut carry);
+
+            let next = current.borrow().next.clone().unwrap();
+            current = next;
+        }
+
+        // carry now holds what was at the back, and the head is waiting for it
+        let head = self.sentinel.borrow().next.clone().unwrap();
+        head.borrow_mut().data = carry;
+    }
+
+    /// Rotates the list so that the first occurrence of `target` becomes the front. Only the
+    /// sentinel is relinked, none of the data is moved.
+    ///
+    /// ## Parameters
+    ///
+    /// - `target: &T` is the data to rotate to the front.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Ok(())` if the list was rotated.
+    /// 2. `Err(ListError::NotFound)` if `target` isn't in the list.
+    pub fn rotate_to_value(&mut self, target: &T) -> Result<(), ListError> {
+        let new_head = self.find_node(target).ok_or(ListError::NotFound)?;
+        self.move_sentinel_before(&new_head);
+        Ok(())
+    }
+
+    /// Takes the sentinel out of the ring and puts it back in directly before `node`, making `node`
+    /// the new front of the list. `node` must not be the sentinel.
+    fn move_sentinel_before(&mut self, node: &Rc<RefCell<Node<T>>>) {
+        let head = self.sentinel.borrow().next.clone().unwrap();
+        let tail = self.sentinel.borrow().prev_node().unwrap();
+
+        // Close the ring up without the sentinel
+        tail.borrow_mut().next = Some(Rc::clone(&head));
+        head.borrow_mut().prev = Some(Rc::downgrade(&tail));
+
+        // Then open it back up in front of the new head
+        let new_tail = node.borrow().prev_node().unwrap();
+        new_tail.borrow_mut().next = Some(Rc::clone(&self.sentinel));
+        node.borrow_mut().prev = Some(Rc::downgrade(&self.sentinel));
+        self.sentinel.borrow_mut().next = Some(Rc::clone(node));
+        self.sentinel.borrow_mut().prev = Some(Rc::downgrade(&new_tail));
+    }
+
+    /// Splices `node` out of the ring and moves its data out. `node` must not be the sentinel.
+    fn unlink_node(&mut self, node: &Rc<RefCell<Node<T>>>) -> T {
+        let prev = node.borrow().prev_node().unwrap();
+        let next = node.borrow_mut().next.take().unwrap();
+
+        prev.borrow_mut().next = Some(Rc::clone(&next));
+        next.borrow_mut().prev = Some(Rc::downgrade(&prev));
+
+        self.size -= 1;
+
+        node.borrow_mut().data.take().unwrap()
+    }
+
+    /// Removes the data at the front of the list.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is the data that was at the front of the list.
+    /// 2. `None` if the list is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
         if self.is_empty() {
             None
         } else {
-            let mut index: usize = 0;
-            let mut current = self.sentinel.borrow().next.clone();
-
-            // While there exists a node
-            while let Some(node) = current {
-                // Break and return None if we wrap back around to the sentinel node without
-                // finding our target data
-                //
-                // Changed from a pointer equivalency check to an index/size check under suggestion
-                // from Michael Olasci
-                if index == self.size {
-                    break;
-                }
-
-                // If we found our target, return the current index
-                if node.borrow().data == data {
-                    return Some(index);
-                }
+            let head = self.sentinel.borrow().next.clone().unwrap();
+            let next = head.borrow().next.clone();
 
-                // If we didn't find the target, and we're not on the sentinel node, increment
-                current = node.borrow().next.clone();
-                index += 1;
+            // Point the sentinel and the node after the head at each other. When the head was the
+            // only node, next is the sentinel and this restores the empty ring.
+            if let Some(next_node) = next.clone() {
+                next_node.borrow_mut().prev = Some(Rc::downgrade(&self.sentinel));
             }
+            self.sentinel.borrow_mut().next = next;
 
-            None
+            self.size -= 1;
+
+            // Nothing points at the old head anymore, so the data can be moved out of it
+            let data = head.borrow_mut().data.take();
+            data
         }
     }
-}
 
-// This is Rust's version of toString
-impl<T: PartialEq + Default + Display + Clone> Display for List<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut print_str: String = String::new();
-        let mut current = self.sentinel.borrow().next.clone();
-        while let Some(node) = current {
-            if Rc::ptr_eq(&node, &self.sentinel) {
-                break;
+    /// Removes the data at the back of the list.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is the data that was at the back of the list.
+    /// 2. `None` if the list is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            let tail = self.sentinel.borrow().prev_node().unwrap();
+            let prev = tail.borrow().prev_node();
+
+            // Point the sentinel and the node before the tail at each other. When the tail was the
+            // only node, prev is the sentinel and this restores the empty ring.
+            if let Some(prev_node) = prev.clone() {
+                prev_node.borrow_mut().next = Some(Rc::clone(&self.sentinel));
             }
+            self.sentinel.borrow_mut().prev = prev.as_ref().map(Rc::downgrade);
 
-            print_str = format!("{}{} -> ", print_str, node.borrow().data);
-            current = node.borrow().next.clone();
+            self.size -= 1;
+
+            // Nothing points at the old tail anymore, so the data can be moved out of it
+            let data = tail.borrow_mut().data.take();
+            data
         }
+    }
 
-        print_str = format!("{}(sentinel)\n", print_str);
-        write!(f, "{}", print_str)
+    /// Removes the data at the specified index. If index is invalid then this function does
+    /// nothing and returns an error.
+    ///
+    /// ## Parameters
+    ///
+    /// - `index: usize` is the index of the data to remove.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Ok(data)` where `data` is the data that was removed.
+    /// 2. `Err(ListError::Empty)` if the list is empty.
+    /// 3. `Err(ListError::IndexOutOfBounds)` if `index` is past the end of the list.
+    pub fn remove_index(&mut self, index: usize) -> Result<T, ListError> {
+        if self.is_empty() {
+            Err(ListError::Empty)
+        } else if index >= self.size {
+            Err(ListError::IndexOutOfBounds)
+        } else {
+            // The bounds were already checked so the node is always found
+            let node = self.node_at(index).unwrap();
+            Ok(self.unlink_node(&node))
+        }
+    }
+
+    /// Removes the data at each of the specified indices in a single pass. The indices all refer
+    /// to positions in the list before anything is removed, so they don't shift as elements are
+    /// taken out. If any index is invalid then this function does nothing and returns an error.
+    ///
+    /// ## Parameters
+    ///
+    /// - `indices: &[usize]` is the indices of the data to remove, in any order.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Ok(removed)` where `removed` holds the removed data in the same order as `indices`.
+    /// 2. `Err(ListError::Empty)` if the list is empty and `indices` isn't.
+    /// 3. `Err(ListError::IndexOutOfBounds)` if any index is past the end of the list.
+    /// 4. `Err(ListError::DuplicateIndex)` if any index appears more than once.
+    pub fn remove_indices(&mut self, indices: &[usize]) -> Result<Vec<T>, ListError> {
+        if indices.is_empty() {
+            return Ok(Vec::new());
+        } else if self.is_empty() {
+            return Err(ListError::Empty);
+        }
+
+        // Positions in indices, ordered by the index they hold, so the list can be walked once
+        let mut order: Vec<usize> = (0..indices.len()).collect();
+        order.sort_by_key(|&i| indices[i]);
+
+        if indices[*order.last().unwrap()] >= self.size {
+            return Err(ListError::IndexOutOfBounds);
+        } else if order.windows(2).any(|w| indices[w[0]] == indices[w[1]]) {
+            return Err(ListError::DuplicateIndex);
+        }
+
+        let mut removed: Vec<Option<T>> = (0..indices.len()).map(|_| None).collect();
+        let mut pending = order.into_iter().peekable();
+        let mut current = self.sentinel.borrow().next.clone().unwrap();
+        let mut index: usize = 0;
+
+        while let Some(&position) = pending.peek() {
+            // Grab the next node before current is possibly unlinked
+            let next = current.borrow().next.clone().unwrap();
+
+            if indices[position] == index {
+                removed[position] = Some(self.unlink_node(&current));
+                pending.next();
+            }
+
+            current = next;
+            index += 1;
+        }
+
+        Ok(removed.into_iter().map(Option::unwrap).collect())
+    }
+
+    /// Removes the data at the specified index by moving the last element into its place, like
+    /// `Vec::swap_remove`. This doesn't keep the order of the list, but only the tail node is
+    /// unlinked. If index is invalid then this function does nothing and returns an error.
+    ///
+    /// ## Parameters
+    ///
+    /// - `index: usize` is the index of the data to remove.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Ok(data)` where `data` is the data that was removed.
+    /// 2. `Err(ListError::Empty)` if the list is empty.
+    /// 3. `Err(ListError::IndexOutOfBounds)` if `index` is past the end of the list.
+    pub fn swap_remove(&mut self, index: usize) -> Result<T, ListError> {
+        if self.is_empty() {
+            return Err(ListError::Empty);
+        } else if index >= self.size {
+            return Err(ListError::IndexOutOfBounds);
+        }
+
+        let tail = self.pop_back().unwrap();
+        if index == self.size {
+            // The element being removed was the tail itself
+            return Ok(tail);
+        }
+
+        let node = self.node_at(index).unwrap();
+        let old = node.borrow_mut().data.replace(tail).unwrap();
+        Ok(old)
+    }
+
+    /// Removes the elements from `start` up to but not including `end`. The whole range is spliced
+    /// out at once by linking the node before `start` to the node at `end`. If the range is invalid
+    /// then this function does nothing and returns an error.
+    ///
+    /// ## Parameters
+    ///
+    /// - `start: usize` is the index of the first element to remove.
+    /// - `end: usize` is the index just past the last element to remove.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Ok(removed)` where `removed` holds the data that was removed, in order.
+    /// 2. `Err(ListError::InvalidRange)` if `start` is greater than `end`.
+    /// 3. `Err(ListError::IndexOutOfBounds)` if `end` is greater than the size of the list.
+    pub fn remove_range(&mut self, start: usize, end: usize) -> Result<Vec<T>, ListError> {
+        if start > end {
+            return Err(ListError::InvalidRange);
+        } else if end > self.size {
+            return Err(ListError::IndexOutOfBounds);
+        }
+
+        let before = if start == 0 {
+            Rc::clone(&self.sentinel)
+        } else {
+            self.node_at(start - 1).unwrap()
+        };
+
+        // Move the data out of each node in the range, cutting their next links as we go
+        let mut removed = Vec::with_capacity(end - start);
+        let mut current = before.borrow().next.clone().unwrap();
+        for _ in start..end {
+            let next = current.borrow_mut().next.take().unwrap();
+            removed.push(current.borrow_mut().data.take().unwrap());
+            current = next;
+        }
+
+        // current is now the node at end, which is the sentinel if the range ran to the back
+        before.borrow_mut().next = Some(Rc::clone(&current));
+        current.borrow_mut().prev = Some(Rc::downgrade(&before));
+        self.size -= end - start;
+
+        Ok(removed)
+    }
+
+    /// Removes the first occurrence of `data` from the list. If `data` isn't in the list then this
+    /// function does nothing and returns `None`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `data: &T` is the data to remove.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(index)` where `index` is the location the data was removed from.
+    /// 2. `None` if the target data couldn't be found.
+    pub fn remove_value(&mut self, data: &T) -> Option<usize> {
+        let index = self.index_of(data.clone())?;
+        self.remove_index(index).ok()?;
+        Some(index)
+    }
+
+    /// Removes the first element that satisfies a predicate.
+    ///
+    /// ## Parameters
+    ///
+    /// - `pred: F` is a function that returns `true` for the element to remove.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is the data that was removed.
+    /// 2. `None` if no element matches, in which case nothing is removed.
+    pub fn remove_if_first<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> Option<T> {
+        let mut current = self.sentinel.borrow().next.clone().unwrap();
+
+        while !Rc::ptr_eq(&current, &self.sentinel) {
+            let found = pred(current.borrow().value());
+            if found {
+                return Some(self.unlink_node(&current));
+            }
+
+            let next = current.borrow().next.clone().unwrap();
+            current = next;
+        }
+
+        None
+    }
+
+    /// Search for any occurrence of `data` from the list. Internally, this function will call
+    /// `compare_to` on each item in the list until a match is found or the end of the list is
+    /// reached. If there are multiple copies of the same data in the list, the first one will be
+    /// returned.
+    ///
+    /// ## Parameters
+    ///
+    /// - `data: usize` is the data to search for.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(index)` where `index` is the location of the target data.
+    /// 2. `None` if the target data couldn't be found.
+    pub fn index_of(&self, data: T) -> Option<usize> {
+        if self.is_empty() {
+            None
+        } else {
+            let mut index: usize = 0;
+            let mut current = self.sentinel.borrow().next.clone();
+
+            // While there exists a node
+            while let Some(node) = current {
+                // Break and return None if we wrap back around to the sentinel node without
+                // finding our target data
+                //
+                // Changed from a pointer equivalency check to an index/size check under suggestion
+                // from Michael Olasci
+                if index == self.size {
+                    break;
+                }
+
+                // If we found our target, return the current index
+                if self.data_eq(node.borrow().value(), &data) {
+                    return Some(index);
+                }
+
+                // If we didn't find the target, and we're not on the sentinel node, increment
+                current = node.borrow().next.clone();
+                index += 1;
+            }
+
+            None
+        }
+    }
+
+    /// Determines if `data` is in the list.
+    ///
+    /// ## Parameters
+    ///
+    /// - `data: &T` is the data to search for.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `true` if the list holds an element equal to `data`.
+    /// 2. `false` if it doesn't.
+    pub fn contains(&self, data: &T) -> bool {
+        self.index_of(data.clone()).is_some()
+    }
+
+    /// Looks for `data` in the list and adds it to the back if it isn't there yet, which makes it
+    /// easy to use the list like a set.
+    ///
+    /// ## Parameters
+    ///
+    /// - `data: T` is the data to look for or add.
+    ///
+    /// ## Returns
+    ///
+    /// The index of the first element equal to `data`, or the index it was added at.
+    pub fn get_or_insert(&mut self, data: T) -> usize {
+        match self.index_of(data.clone()) {
+            Some(index) => index,
+            None => {
+                self.push_back(data);
+                self.size - 1
+            }
+        }
+    }
+
+    /// Compares two elements with the user provided comparator when there is one, and with
+    /// `PartialEq` otherwise.
+    fn data_eq(&self, a: &T, b: &T) -> bool {
+        match self.compare {
+            Some(cmp) => cmp(a, b) == Ordering::Equal,
+            None => a == b,
+        }
+    }
+
+    /// Searches for the first element that satisfies a predicate.
+    ///
+    /// ## Parameters
+    ///
+    /// - `pred: F` is a function that returns `true` for the element being searched for.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is a clone of the first matching element.
+    /// 2. `None` if no element matches.
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<T> {
+        self.iter().find(|data| pred(data))
+    }
+
+    /// Searches for the index of the first element that satisfies a predicate. This is the same as
+    /// `index_of` but with a custom test instead of equality.
+    ///
+    /// ## Parameters
+    ///
+    /// - `pred: F` is a function that returns `true` for the element being searched for.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(index)` where `index` is the location of the first matching element.
+    /// 2. `None` if no element matches.
+    pub fn position<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<usize> {
+        self.iter().position(|data| pred(&data))
+    }
+
+    /// Determines if any element satisfies a predicate. Stops at the first match.
+    ///
+    /// ## Parameters
+    ///
+    /// - `pred: F` is the function to test each element with.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `true` if at least one element matches.
+    /// 2. `false` if no element matches, which is always the case for an empty list.
+    pub fn any<F: FnMut(&T) -> bool>(&self, mut pred: F) -> bool {
+        self.iter().any(|data| pred(&data))
+    }
+
+    /// Determines if every element satisfies a predicate. Stops at the first element that doesn't.
+    ///
+    /// ## Parameters
+    ///
+    /// - `pred: F` is the function to test each element with.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `true` if every element matches, which is always the case for an empty list.
+    /// 2. `false` if at least one element doesn't match.
+    pub fn all<F: FnMut(&T) -> bool>(&self, mut pred: F) -> bool {
+        self.iter().all(|data| pred(&data))
+    }
+
+    /// Counts how many times `data` appears in the list, using the list's comparator if it has
+    /// one.
+    ///
+    /// ## Parameters
+    ///
+    /// - `data: &T` is the data to count.
+    ///
+    /// ## Returns
+    ///
+    /// The number of elements equal to `data`, which is 0 for an empty list.
+    pub fn count(&self, data: &T) -> usize {
+        self.iter().filter(|x| self.data_eq(x, data)).count()
+    }
+
+    /// Counts how many elements satisfy a predicate.
+    ///
+    /// ## Parameters
+    ///
+    /// - `pred: F` is a function that returns `true` for the elements to count.
+    ///
+    /// ## Returns
+    ///
+    /// The number of matching elements, which is 0 for an empty list.
+    pub fn count_if<F: FnMut(&T) -> bool>(&self, mut pred: F) -> usize {
+        self.iter().filter(|x| pred(x)).count()
+    }
+
+    /// Determines if two lists hold the same elements the same number of times, in any order.
+    /// Elements of both lists are compared with this list's comparator if it has one.
+    ///
+    /// ## Parameters
+    ///
+    /// - `other: &List<T>` is the list to compare against.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `true` if each list is a permutation of the other.
+    /// 2. `false` if the lists differ in size or in how often some element appears.
+    pub fn eq_ignore_order(&self, other: &List<T>) -> bool {
+        self.size == other.size
+            && self.iter().all(|data| {
+                let theirs = other.iter().filter(|x| self.data_eq(x, &data)).count();
+                self.count(&data) == theirs
+            })
+    }
+
+    /// Determines if the list begins with the elements of `prefix`, in the same order. Elements are
+    /// compared with the list's comparator if it has one.
+    ///
+    /// ## Parameters
+    ///
+    /// - `prefix: &[T]` is the data to look for at the front of the list.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `true` if the first `prefix.len()` elements equal `prefix`, which is always the case
+    ///    for an empty `prefix`.
+    /// 2. `false` otherwise, including when `prefix` is longer than the list.
+    pub fn starts_with(&self, prefix: &[T]) -> bool {
+        prefix.len() <= self.size
+            && self
+                .iter()
+                .zip(prefix)
+                .all(|(data, x)| self.data_eq(&data, x))
+    }
+
+    /// Determines if the list ends with the elements of `suffix`, in the same order. Elements are
+    /// compared with the list's comparator if it has one.
+    ///
+    /// ## Parameters
+    ///
+    /// - `suffix: &[T]` is the data to look for at the back of the list.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `true` if the last `suffix.len()` elements equal `suffix`, which is always the case
+    ///    for an empty `suffix`.
+    /// 2. `false` otherwise, including when `suffix` is longer than the list.
+    pub fn ends_with(&self, suffix: &[T]) -> bool {
+        suffix.len() <= self.size
+            && self
+                .iter_rev()
+                .zip(suffix.iter().rev())
+                .all(|(data, x)| self.data_eq(&data, x))
+    }
+
+    /// Gets the data at the specified index. The index is counted from the front of the list,
+    /// meaning index 0 is the node directly after the sentinel.
+    ///
+    /// ## Parameters
+    ///
+    /// - `index: usize` is the index of the data to get.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is a clone of the data stored at `index`.
+    /// 2. `None` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<T> {
+        let node = self.node_at(index)?;
+        let data = node.borrow().data.clone();
+        data
+    }
+
+    /// Gets the data at the specified index, where negative indices count from the back like in
+    /// Python. Index -1 is the last element, -2 the one before it, and so on. Non-negative indices
+    /// behave the same as `get`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `index: isize` is the index of the data to get.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is a clone of the data stored at `index`.
+    /// 2. `None` if `index` is out of bounds in either direction.
+    pub fn get_signed(&self, index: isize) -> Option<T> {
+        if index < 0 {
+            self.nth_back(index.unsigned_abs() - 1)
+        } else {
+            self.get(index.unsigned_abs())
+        }
+    }
+
+    /// Replaces the data at the specified index. The links and size of the list are unchanged.
+    ///
+    /// ## Parameters
+    ///
+    /// - `index: usize` is the index of the data to replace.
+    /// - `data: T` is the new data.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Ok(old)` where `old` is the data that was replaced.
+    /// 2. `Err(ListError::IndexOutOfBounds)` if `index` is past the end of the list.
+    pub fn update(&mut self, index: usize, data: T) -> Result<T, ListError> {
+        let node = self.node_at(index).ok_or(ListError::IndexOutOfBounds)?;
+        let old = node.borrow_mut().data.replace(data).unwrap();
+        Ok(old)
+    }
+
+    /// Gets a handle to the node at the specified index, for building on top of the list. The
+    /// node is shared with the list, not copied, so writing its `data` through the handle changes
+    /// what `get`, `iter`, and every other read of the list see at that index. Relinking it or
+    /// adding and removing nodes through it bypasses the list's bookkeeping and will leave `len`
+    /// wrong.
+    ///
+    /// ## Parameters
+    ///
+    /// - `index: usize` is the index of the node to get.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(node)` where `node` is a new `Rc` pointing at the node at `index`.
+    /// 2. `None` if `index` is out of bounds.
+    pub fn get_node_ref(&self, index: usize) -> Link<T> {
+        self.node_at(index)
+    }
+
+    /// Finds the node at the specified index, never returning the sentinel.
+    fn node_at(&self, index: usize) -> Link<T> {
+        if index >= self.size {
+            None
+        } else {
+            let mut i: usize = 0;
+            let mut current = self.sentinel.borrow().next.clone();
+
+            while let Some(node) = current {
+                // Stop before wrapping back around to the sentinel node
+                if i == self.size {
+                    break;
+                }
+
+                if i == index {
+                    return Some(node);
+                }
+
+                current = node.borrow().next.clone();
+                i += 1;
+            }
+
+            None
+        }
+    }
+
+    /// Gets the data at the front of the list without removing it.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is a clone of the data at the front of the list.
+    /// 2. `None` if the list is empty.
+    pub fn peek_front(&self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            let head = self.sentinel.borrow().next.clone().unwrap();
+            let data = head.borrow().data.clone();
+            data
+        }
+    }
+
+    /// Gets the data at the back of the list without removing it.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is a clone of the data at the back of the list.
+    /// 2. `None` if the list is empty.
+    pub fn peek_back(&self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            let tail = self.sentinel.borrow().prev_node().unwrap();
+            let data = tail.borrow().data.clone();
+            data
+        }
+    }
+
+    /// Gets the first element of the list. Same as `peek_front`.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is a clone of the first element.
+    /// 2. `None` if the list is empty.
+    pub fn front(&self) -> Option<T> {
+        self.peek_front()
+    }
+
+    /// Gets the last element of the list. Same as `peek_back`.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is a clone of the last element.
+    /// 2. `None` if the list is empty.
+    pub fn back(&self) -> Option<T> {
+        self.peek_back()
+    }
+
+    /// Gets the first element of the list, falling back to `default` if the list is empty.
+    ///
+    /// ## Parameters
+    ///
+    /// - `default: T` is the data to return when the list is empty.
+    ///
+    /// ## Returns
+    ///
+    /// A clone of the first element, or `default` if there isn't one.
+    pub fn first_or(&self, default: T) -> T {
+        self.front().unwrap_or(default)
+    }
+
+    /// Gets the last element of the list, falling back to `default` if the list is empty.
+    ///
+    /// ## Parameters
+    ///
+    /// - `default: T` is the data to return when the list is empty.
+    ///
+    /// ## Returns
+    ///
+    /// A clone of the last element, or `default` if there isn't one.
+    pub fn last_or(&self, default: T) -> T {
+        self.back().unwrap_or(default)
+    }
+
+    /// Gets the data `n` positions from the back of the list by following the `prev` links from
+    /// the tail, so index 0 is the last element.
+    ///
+    /// ## Parameters
+    ///
+    /// - `n: usize` is how far from the back the data is.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is a clone of the data `n` positions from the back.
+    /// 2. `None` if `n` is out of bounds.
+    pub fn nth_back(&self, n: usize) -> Option<T> {
+        if n >= self.size {
+            return None;
+        }
+
+        let mut current = self.sentinel.borrow().prev_node().unwrap();
+        for _ in 0..n {
+            let prev = current.borrow().prev_node().unwrap();
+            current = prev;
+        }
+
+        let data = current.borrow().data.clone();
+        data
+    }
+
+    /// Shortens the list to at most `len` elements, dropping everything after that. Does nothing
+    /// if the list already has `len` or fewer elements.
+    ///
+    /// ## Parameters
+    ///
+    /// - `len: usize` is the number of elements to keep.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.size {
+            return;
+        }
+
+        // The sentinel stands in for the new tail when everything is being dropped
+        let new_tail = if len == 0 {
+            Rc::clone(&self.sentinel)
+        } else {
+            self.node_at(len - 1).unwrap()
+        };
+        let mut current = new_tail.borrow().next.clone();
+
+        new_tail.borrow_mut().next = Some(Rc::clone(&self.sentinel));
+        self.sentinel.borrow_mut().prev = Some(Rc::downgrade(&new_tail));
+
+        // The dropped nodes still point at each other, so break their links to free them
+        for _ in len..self.size {
+            let node = current.unwrap();
+            node.borrow_mut().prev = None;
+            current = node.borrow_mut().next.take();
+        }
+
+        self.size = len;
+    }
+
+    /// Removes all of the data from the list, leaving it in the same state as `new_list`.
+    pub fn clear(&mut self) {
+        self.unlink_all();
+
+        self.sentinel.borrow_mut().next = Some(Rc::clone(&self.sentinel));
+        self.sentinel.borrow_mut().prev = Some(Rc::downgrade(&self.sentinel));
+        self.size = 0;
+    }
+
+    /// Moves all of the data out into a new list, leaving this one in the same state as `new_list`.
+    /// The new list takes over the existing ring, sentinel and all, so no nodes are touched.
+    ///
+    /// ## Returns
+    ///
+    /// A list holding everything that was in this one, using the same comparator.
+    pub fn take(&mut self) -> List<T> {
+        let empty = self.new_like();
+        core::mem::replace(self, empty)
+    }
+
+    /// Walks the ring once and sets every `next` and `prev` link to `None`, including the
+    /// sentinel's. The `prev` links are weak, but following `next` still leads back around to the
+    /// sentinel, so the ring has to be broken by hand or none of the nodes would ever be freed.
+    /// Taking the links one node at a time also keeps a long list from being freed recursively.
+    fn unlink_all(&mut self) {
+        let mut current = self.sentinel.borrow_mut().next.take();
+        while let Some(node) = current {
+            if Rc::ptr_eq(&node, &self.sentinel) {
+                break;
+            }
+
+            node.borrow_mut().prev = None;
+            current = node.borrow_mut().next.take();
+        }
+
+        self.sentinel.borrow_mut().prev = None;
+    }
+
+    /// Reverses the order of the list in place by swapping the `next` and `prev` links of every
+    /// node, including the sentinel. No nodes are allocated or freed.
+    pub fn reverse(&mut self) {
+        // Once the sentinel is flipped nothing owns the old head until the node after it is
+        // flipped at the very end, so hold on to it until then
+        let _head = self.sentinel.borrow().next.clone();
+        let mut current = Rc::clone(&self.sentinel);
+
+        // Walk backwards so that every node's new owner, its old prev node, is still reachable.
+        // Visit each node once, plus the sentinel.
+        for _ in 0..=self.size {
+            let prev = {
+                let mut node = current.borrow_mut();
+                let old_prev = node.prev_node().unwrap();
+                let old_next = node.next.replace(Rc::clone(&old_prev));
+                node.prev = old_next.as_ref().map(Rc::downgrade);
+
+                old_prev
+            };
+            current = prev;
+        }
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, removing the rest. The order of the
+    /// kept elements is preserved.
+    ///
+    /// ## Parameters
+    ///
+    /// - `f: F` is a function that decides whether an element should be kept.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut current = self.sentinel.borrow().next.clone().unwrap();
+
+        while !Rc::ptr_eq(&current, &self.sentinel) {
+            // Grab the next node before current is possibly unlinked
+            let next = current.borrow().next.clone().unwrap();
+
+            let keep = f(current.borrow().value());
+            if !keep {
+                self.unlink_node(&current);
+            }
+
+            current = next;
+        }
+    }
+
+    /// Removes every occurrence of `data` from the list in a single pass, using the list's
+    /// comparator if it has one.
+    ///
+    /// ## Parameters
+    ///
+    /// - `data: &T` is the data to remove.
+    ///
+    /// ## Returns
+    ///
+    /// The number of elements that were removed.
+    pub fn remove_all(&mut self, data: &T) -> usize {
+        let mut removed: usize = 0;
+        let mut current = self.sentinel.borrow().next.clone().unwrap();
+
+        while !Rc::ptr_eq(&current, &self.sentinel) {
+            // Grab the next node before current is possibly unlinked
+            let next = current.borrow().next.clone().unwrap();
+
+            let matches = self.data_eq(current.borrow().value(), data);
+            if matches {
+                self.unlink_node(&current);
+                removed += 1;
+            }
+
+            current = next;
+        }
+
+        removed
+    }
+
+    /// Overwrites every element equal to `old` with a clone of `new`, using the list's comparator
+    /// if it has one. Only the data changes, the links and size of the list are untouched.
+    ///
+    /// ## Parameters
+    ///
+    /// - `old: &T` is the data to replace.
+    /// - `new: T` is the data to replace it with.
+    ///
+    /// ## Returns
+    ///
+    /// The number of elements that were replaced.
+    pub fn replace_all(&mut self, old: &T, new: T) -> usize {
+        let mut replaced: usize = 0;
+        let mut current = self.sentinel.borrow().next.clone().unwrap();
+
+        while !Rc::ptr_eq(&current, &self.sentinel) {
+            let matches = self.data_eq(current.borrow().value(), old);
+            if matches {
+                current.borrow_mut().data = Some(new.clone());
+                replaced += 1;
+            }
+
+            let next = current.borrow().next.clone().unwrap();
+            current = next;
+        }
+
+        replaced
+    }
+
+    /// Removes consecutive repeated elements, keeping the first of each run. Elements are compared
+    /// with the list's comparator if it has one. Duplicates that aren't next to each other are left
+    /// alone.
+    pub fn dedup(&mut self) {
+        if self.size < 2 {
+            return;
+        }
+
+        let mut kept = self.sentinel.borrow().next.clone().unwrap();
+        let mut current = kept.borrow().next.clone().unwrap();
+
+        while !Rc::ptr_eq(&current, &self.sentinel) {
+            // Grab the next node before current is possibly unlinked
+            let next = current.borrow().next.clone().unwrap();
+
+            let duplicate = self.data_eq(current.borrow().value(), kept.borrow().value());
+            if duplicate {
+                self.unlink_node(&current);
+            } else {
+                kept = current;
+            }
+
+            current = next;
+        }
+    }
+
+    /// Removes consecutive elements that map to the same key, keeping the first of each run. Each
+    /// element is compared against the key of the last element that was kept.
+    ///
+    /// ## Parameters
+    ///
+    /// - `key: F` is the function that picks out the key of an element.
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        if self.size < 2 {
+            return;
+        }
+
+        let first = self.sentinel.borrow().next.clone().unwrap();
+        let mut kept_key = key(first.borrow().value());
+        let mut current = first.borrow().next.clone().unwrap();
+
+        while !Rc::ptr_eq(&current, &self.sentinel) {
+            // Grab the next node before current is possibly unlinked
+            let next = current.borrow().next.clone().unwrap();
+
+            let current_key = key(current.borrow().value());
+            if current_key == kept_key {
+                self.unlink_node(&current);
+            } else {
+                kept_key = current_key;
+            }
+
+            current = next;
+        }
+    }
+
+    /// Counts the runs of consecutive equal elements, from front to back, like run-length
+    /// encoding. Elements are compared with the list's comparator if it has one, and each run
+    /// reports its first element. Equal elements that aren't next to each other are counted as
+    /// separate runs.
+    ///
+    /// ## Returns
+    ///
+    /// A `Vec` of `(data, length)` pairs where `data` is a clone of the element repeated in the run
+    /// and `length` is how many times it repeats.
+    pub fn group_consecutive(&self) -> Vec<(T, usize)> {
+        let mut groups: Vec<(T, usize)> = Vec::new();
+
+        for data in self.iter() {
+            match groups.last_mut() {
+                Some((first, length)) if self.data_eq(first, &data) => *length += 1,
+                _ => groups.push((data, 1)),
+            }
+        }
+
+        groups
+    }
+
+    /// Sorts the list using a comparator function. The sort is stable, so elements the comparator
+    /// considers equal keep their relative order.
+    ///
+    /// ## Parameters
+    ///
+    /// - `compare: F` is a function that returns the `Ordering` of two elements.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, compare: F) {
+        if self.size < 2 {
+            return;
+        }
+
+        let mut sorted = self.to_vec();
+        sorted.sort_by(compare);
+
+        // Write the sorted data back into the existing nodes so nothing needs to be relinked
+        let mut current = self.sentinel.borrow().next.clone();
+        for data in sorted {
+            let node = current.unwrap();
+            node.borrow_mut().data = Some(data);
+            current = node.borrow().next.clone();
+        }
+    }
+
+    /// Creates a new list by applying a function to every element of this one, in order.
+    ///
+    /// ## Parameters
+    ///
+    /// - `f: F` is the function to apply to each element.
+    ///
+    /// ## Returns
+    ///
+    /// A `List<U>` holding the result of `f` for each element.
+    pub fn map<U: PartialEq + Clone, F: FnMut(&T) -> U>(&self, mut f: F) -> List<U> {
+        self.iter().map(|data| f(&data)).collect()
+    }
+
+    /// Combines every element into a single value, from front to back. The elements are borrowed
+    /// straight out of the nodes rather than cloned.
+    ///
+    /// ## Parameters
+    ///
+    /// - `init: B` is the starting value of the accumulator.
+    /// - `f: F` is a function that takes the accumulator and an element and returns the new
+    ///   accumulator.
+    ///
+    /// ## Returns
+    ///
+    /// The final accumulator, which is `init` for an empty list.
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, mut f: F) -> B {
+        let mut acc = init;
+        let mut current = self.sentinel.borrow().next.clone().unwrap();
+
+        while !Rc::ptr_eq(&current, &self.sentinel) {
+            acc = f(acc, current.borrow().value());
+
+            let next = current.borrow().next.clone().unwrap();
+            current = next;
+        }
+
+        acc
+    }
+
+    /// Combines every element into a single value like `fold`, but stops at the first element
+    /// `f` fails on.
+    ///
+    /// ## Parameters
+    ///
+    /// - `init: B` is the starting value of the accumulator.
+    /// - `f: F` is a function that takes the accumulator and an element and returns the new
+    ///   accumulator or an error.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Ok(acc)` where `acc` is the final accumulator, which is `init` for an empty list.
+    /// 2. `Err(err)` where `err` is the first error returned by `f`.
+    pub fn try_fold<B, E, F: FnMut(B, &T) -> Result<B, E>>(
+        &self,
+        init: B,
+        mut f: F,
+    ) -> Result<B, E> {
+        let mut acc = init;
+        let mut current = self.sentinel.borrow().next.clone().unwrap();
+
+        while !Rc::ptr_eq(&current, &self.sentinel) {
+            acc = f(acc, current.borrow().value())?;
+
+            let next = current.borrow().next.clone().unwrap();
+            current = next;
+        }
+
+        Ok(acc)
+    }
+
+    /// Applies a function to every element in place, from front to back. The sentinel is never
+    /// touched.
+    ///
+    /// ## Parameters
+    ///
+    /// - `f: F` is the function to apply to each element.
+    pub fn for_each_mut<F: FnMut(&mut T)>(&mut self, mut f: F) {
+        let mut current = self.sentinel.borrow().next.clone().unwrap();
+
+        while !Rc::ptr_eq(&current, &self.sentinel) {
+            f(current.borrow_mut().value_mut());
+
+            let next = current.borrow().next.clone().unwrap();
+            current = next;
+        }
+    }
+
+    /// Splits a copy of the list in two at `index`, leaving this list unchanged.
+    ///
+    /// ## Parameters
+    ///
+    /// - `index: usize` is the index the second list starts at.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Ok((front, back))` where `front` holds the elements before `index` and `back` holds the
+    ///    rest.
+    /// 2. `Err(ListError::IndexOutOfBounds)` if `index` is greater than the size of the list.
+    pub fn split_at(&self, index: usize) -> Result<(List<T>, List<T>), ListError> {
+        if index > self.size {
+            return Err(ListError::IndexOutOfBounds);
+        }
+
+        let mut front = self.new_like();
+        let mut back = self.new_like();
+        front.extend(self.iter().take(index));
+        back.extend(self.iter().skip(index));
+
+        Ok((front, back))
+    }
+
+    /// Splits a copy of the list into pieces wherever `pred` matches, leaving this list unchanged.
+    /// The matching elements are dropped. Like `slice::split`, there is always one more piece than
+    /// there are matches, so a match at either end or two matches in a row produce an empty piece.
+    ///
+    /// ## Parameters
+    ///
+    /// - `pred: F` is the function that picks out the separators.
+    ///
+    /// ## Returns
+    ///
+    /// A `Vec` of the pieces, in order.
+    pub fn split_when<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Vec<List<T>> {
+        let mut pieces = Vec::new();
+        let mut piece = self.new_like();
+
+        for data in self.iter() {
+            if pred(&data) {
+                pieces.push(core::mem::replace(&mut piece, self.new_like()));
+            } else {
+                piece.push_back(data);
+            }
+        }
+        pieces.push(piece);
+
+        pieces
+    }
+
+    /// Copies the elements of the list into two new lists depending on `pred`, leaving this list
+    /// unchanged. Both lists keep the elements in the same relative order.
+    ///
+    /// ## Parameters
+    ///
+    /// - `pred: F` is the function that decides which list each element goes in.
+    ///
+    /// ## Returns
+    ///
+    /// `(matching, rest)` where `matching` holds the elements `pred` returned `true` for and `rest`
+    /// holds the others.
+    pub fn partition<F: FnMut(&T) -> bool>(&self, mut pred: F) -> (List<T>, List<T>) {
+        let mut matching = self.new_like();
+        let mut rest = self.new_like();
+
+        for data in self.iter() {
+            if pred(&data) {
+                matching.push_back(data);
+            } else {
+                rest.push_back(data);
+            }
+        }
+
+        (matching, rest)
+    }
+
+    /// Pairs up the elements of this list and `other` into a new list of tuples, walking both
+    /// lists together from front to back. The result is as long as the shorter list, and neither
+    /// list is changed.
+    ///
+    /// ## Parameters
+    ///
+    /// - `other: &List<U>` is the list holding the second element of each pair.
+    ///
+    /// ## Returns
+    ///
+    /// A new list of `(data, other_data)` pairs.
+    pub fn zip<U: PartialEq + Clone>(&self, other: &List<U>) -> List<(T, U)> {
+        self.iter().zip(other.iter()).collect()
+    }
+
+    /// Copies the elements of this list and `other` into a new list, alternating between the two
+    /// and starting with this one. Once either list runs out, the rest of the other one is added
+    /// on the end. Neither list is changed.
+    ///
+    /// ## Parameters
+    ///
+    /// - `other: &List<T>` is the list to alternate with.
+    ///
+    /// ## Returns
+    ///
+    /// A new list holding every element of both lists.
+    pub fn interleave(&self, other: &List<T>) -> List<T> {
+        let mut combined = self.new_like();
+        let mut ours = self.iter();
+        let mut theirs = other.iter();
+
+        loop {
+            match (ours.next(), theirs.next()) {
+                (None, None) => break,
+                (a, b) => combined.extend(a.into_iter().chain(b)),
+            }
+        }
+
+        combined
+    }
+
+    /// Copies the data in the list into a vector.
+    ///
+    /// ## Returns
+    ///
+    /// A `Vec<T>` holding a clone of each element, in the same order as the list.
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+
+    /// Moves the data in the list into a vector, consuming the list. Unlike `to_vec` nothing is
+    /// cloned.
+    ///
+    /// ## Returns
+    ///
+    /// A `Vec<T>` holding each element, in the same order as the list.
+    pub fn into_vec(self) -> Vec<T> {
+        let mut items = Vec::with_capacity(self.size);
+        items.extend(self);
+        items
+    }
+
+    /// Copies the data in the list into groups of `n` elements, from front to back. The last group
+    /// holds whatever is left over, so it may be shorter than `n`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `n: usize` is the number of elements in each group.
+    ///
+    /// ## Returns
+    ///
+    /// A `Vec` of the groups, which is empty if the list is empty.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n` is 0, the same as `slice::chunks`.
+    pub fn chunks(&self, n: usize) -> Vec<Vec<T>> {
+        self.to_vec().chunks(n).map(<[T]>::to_vec).collect()
+    }
+
+    /// Copies every run of `n` consecutive elements in the list, from front to back. The runs
+    /// overlap, so a list of size `size` has `size - n + 1` of them.
+    ///
+    /// ## Parameters
+    ///
+    /// - `n: usize` is the number of elements in each run.
+    ///
+    /// ## Returns
+    ///
+    /// A `Vec` of the runs, which is empty if `n` is greater than the size of the list.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `n` is 0, the same as `slice::windows`.
+    pub fn windows(&self, n: usize) -> Vec<Vec<T>> {
+        self.to_vec().windows(n).map(<[T]>::to_vec).collect()
+    }
+
+    /// Creates a cursor that starts at the front of the list. If the list is empty the cursor
+    /// starts on the sentinel.
+    ///
+    /// ## Returns
+    ///
+    /// A `Cursor` that can walk the list in both directions and edit it as it goes.
+    pub fn cursor_front(&mut self) -> Cursor<'_, T> {
+        let current = self.sentinel.borrow().next.clone().unwrap();
+        Cursor {
+            list: self,
+            current,
+        }
+    }
+
+    /// Creates an iterator over the data in the list, from front to back.
+    ///
+    /// ## Returns
+    ///
+    /// An `Iter` that yields a clone of each node's data, stopping once it reaches the sentinel.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            current: self.sentinel.borrow().next.clone(),
+        }
+    }
+
+    /// Creates an iterator over the data in the list, starting at `index` and running to the back.
+    /// An `index` past the end is clamped, giving an iterator that yields nothing.
+    ///
+    /// ## Parameters
+    ///
+    /// - `index: usize` is the index of the first element to yield.
+    ///
+    /// ## Returns
+    ///
+    /// An `Iter` that yields a clone of each node's data, stopping once it reaches the sentinel.
+    pub fn iter_from(&self, index: usize) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            current: self
+                .node_at(index)
+                .or_else(|| Some(Rc::clone(&self.sentinel))),
+        }
+    }
+
+    /// Creates an iterator over the data in the list along with each element's index, from front
+    /// to back. Same as `iter().enumerate()`.
+    ///
+    /// ## Returns
+    ///
+    /// An iterator that yields `(index, data)` pairs, where `data` is a clone of the node's data.
+    pub fn iter_indexed(&self) -> Enumerate<Iter<'_, T>> {
+        self.iter().enumerate()
+    }
+
+    /// Creates an iterator over the data in the list, from back to front.
+    ///
+    /// ## Returns
+    ///
+    /// An `IterRev` that yields a clone of each node's data, stopping once it reaches the sentinel.
+    pub fn iter_rev(&self) -> IterRev<'_, T> {
+        IterRev {
+            list: self,
+            current: self.sentinel.borrow().prev_node(),
+        }
+    }
+}
+
+impl<T: PartialEq + Clone + Ord> List<T> {
+    /// Sorts the list in ascending order, using the list's comparator if it has one. The sort is
+    /// stable, so equal elements keep their relative order.
+    pub fn sort(&mut self) {
+        match self.compare {
+            Some(cmp) => self.sort_by(cmp),
+            None => self.sort_by(|a, b| a.cmp(b)),
+        }
+    }
+
+    /// Inserts data into a sorted list so that it stays sorted, using the list's comparator if it
+    /// has one. Since this is a linked list the position is found with a linear scan from the
+    /// front. The new data goes after any elements equal to it.
+    ///
+    /// ## Parameters
+    ///
+    /// - `data: T` is the data to insert.
+    pub fn binary_insert(&mut self, data: T) {
+        let before = self.sorted_position(&data);
+        self.insert_after_node(&before, data);
+    }
+
+    /// Inserts data into a sorted list so that it stays sorted, but only if no equal element is
+    /// already in the list. Equality is decided by the list's comparator if it has one.
+    ///
+    /// ## Parameters
+    ///
+    /// - `data: T` is the data to insert.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `true` if the data was inserted.
+    /// 2. `false` if an equal element was already in the list, in which case nothing changes.
+    pub fn insert_sorted_unique(&mut self, data: T) -> bool {
+        let cmp = self.compare.unwrap_or(T::cmp);
+        let before = self.sorted_position(&data);
+
+        // Any equal element would be the last one that data doesn't sort before
+        let duplicate =
+            !Rc::ptr_eq(&before, &self.sentinel) && cmp(before.borrow().value(), &data).is_eq();
+        if duplicate {
+            return false;
+        }
+
+        self.insert_after_node(&before, data);
+        true
+    }
+
+    /// Merges another sorted list into this sorted list so the result stays sorted, using this
+    /// list's comparator if it has one. The nodes of `other` are spliced in one at a time during a
+    /// single walk, so it takes linear time and none of the data is copied. When elements are equal
+    /// the ones from this list come first.
+    ///
+    /// ## Parameters
+    ///
+    /// - `other: List<T>` is the sorted list to merge in, which is consumed.
+    pub fn merge_sorted(&mut self, mut other: List<T>) {
+        let cmp = self.compare.unwrap_or(T::cmp);
+
+        // The last node of the merged part of the list
+        let mut before = Rc::clone(&self.sentinel);
+
+        while !other.is_empty() {
+            let next = before.borrow().next.clone().unwrap();
+            let incoming = other.sentinel.borrow().next.clone().unwrap();
+
+            // Everything left in other goes after the last element, so splice it on all at once
+            if Rc::ptr_eq(&next, &self.sentinel) {
+                self.append(&mut other);
+                break;
+            }
+
+            if !cmp(next.borrow().value(), incoming.borrow().value()).is_gt() {
+                before = next;
+                continue;
+            }
+
+            // Take the head of other out of its ring
+            let after_incoming = incoming.borrow().next.clone().unwrap();
+            after_incoming.borrow_mut().prev = Some(Rc::downgrade(&other.sentinel));
+            other.sentinel.borrow_mut().next = Some(after_incoming);
+            other.size -= 1;
+
+            // And splice it in between before and next
+            incoming.borrow_mut().prev = Some(Rc::downgrade(&before));
+            incoming.borrow_mut().next = Some(Rc::clone(&next));
+            next.borrow_mut().prev = Some(Rc::downgrade(&incoming));
+            before.borrow_mut().next = Some(Rc::clone(&incoming));
+            self.size += 1;
+
+            before = incoming;
+        }
+    }
+
+    /// Scans a sorted list for the last node that `data` doesn't sort before, which is the sentinel
+    /// if `data` sorts before every element.
+    fn sorted_position(&self, data: &T) -> Rc<RefCell<Node<T>>> {
+        let cmp = self.compare.unwrap_or(T::cmp);
+
+        let mut before = Rc::clone(&self.sentinel);
+        loop {
+            let next = before.borrow().next.clone().unwrap();
+            if Rc::ptr_eq(&next, &self.sentinel) || cmp(next.borrow().value(), data).is_gt() {
+                return before;
+            }
+
+            before = next;
+        }
+    }
+
+    /// Finds the largest element in the list, using the list's comparator if it has one. If there
+    /// are several equally large elements, the last one is returned. Since `List` implements `Ord`,
+    /// `list.max()` on an owned list means `Ord::max`, so call this as `List::max(&list)`.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is a clone of the largest element.
+    /// 2. `None` if the list is empty.
+    pub fn max(&self) -> Option<T> {
+        match self.compare {
+            Some(cmp) => self.iter().max_by(cmp),
+            None => self.iter().max(),
+        }
+    }
+
+    /// Finds the smallest element in the list, using the list's comparator if it has one. If there
+    /// are several equally small elements, the first one is returned. Since `List` implements `Ord`,
+    /// `list.min()` on an owned list means `Ord::min`, so call this as `List::min(&list)`.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is a clone of the smallest element.
+    /// 2. `None` if the list is empty.
+    pub fn min(&self) -> Option<T> {
+        match self.compare {
+            Some(cmp) => self.iter().min_by(cmp),
+            None => self.iter().min(),
+        }
+    }
+}
+
+impl<T: PartialEq + Clone + Sum> List<T> {
+    /// Adds up all of the elements in the list, from front to back.
+    ///
+    /// ## Returns
+    ///
+    /// The sum of the elements, or the additive identity (e.g. `0`) if the list is empty.
+    pub fn sum(&self) -> T {
+        self.iter().sum()
+    }
+}
+
+impl<T: PartialEq + Clone + Product> List<T> {
+    /// Multiplies together all of the elements in the list, from front to back.
+    ///
+    /// ## Returns
+    ///
+    /// The product of the elements, or the multiplicative identity (e.g. `1`) if the list is empty.
+    pub fn product(&self) -> T {
+        self.iter().product()
+    }
+}
+
+impl<T: PartialEq + Clone> List<List<T>> {
+    /// Joins all of the inner lists into one, in order. The inner lists' nodes are spliced in with
+    /// `append`, so none of their data is copied.
+    ///
+    /// ## Returns
+    ///
+    /// A list holding every element of every inner list.
+    pub fn flatten(self) -> List<T> {
+        let mut flat = List::new_list();
+        for mut inner in self {
+            flat.append(&mut inner);
+        }
+
+        flat
+    }
+}
+
+impl<T: PartialEq + Clone + Display> List<T> {
+    /// Formats the list as a string, joining the elements with a custom separator. This is what
+    /// `Display` uses under the hood with a separator of `" -> "` and the sentinel shown.
+    ///
+    /// ## Parameters
+    ///
+    /// - `sep: &str` is the separator to put between elements.
+    /// - `show_sentinel: bool` is whether to end the string with `(sentinel)` and a newline.
+    ///
+    /// ## Returns
+    ///
+    /// The formatted list.
+    pub fn format_with(&self, sep: &str, show_sentinel: bool) -> String {
+        let mut parts: Vec<String> = self.iter().map(|data| data.to_string()).collect();
+        if show_sentinel {
+            parts.push(String::from("(sentinel)"));
+        }
+
+        let mut print_str = parts.join(sep);
+        if show_sentinel {
+            print_str.push('\n');
+        }
+
+        print_str
+    }
+
+    /// Joins the elements with a separator, without the `(sentinel)` marker or trailing newline
+    /// that `Display` adds. Same as `format_with(sep, false)`.
+    ///
+    /// ## Parameters
+    ///
+    /// - `sep: &str` is the separator to put between elements.
+    ///
+    /// ## Returns
+    ///
+    /// The joined elements, which is an empty string for an empty list.
+    pub fn join(&self, sep: &str) -> String {
+        self.format_with(sep, false)
+    }
+}
+
+/// Iterator over the data in a list, from front to back.
+///
+/// Created by `List::iter`. Since the nodes live behind a `RefCell` this can't hand out references,
+/// so each item is a clone of the data stored in the node.
+pub struct Iter<'a, T: PartialEq + Clone> {
+    list: &'a List<T>,
+    current: Link<T>,
+}
+
+impl<T: PartialEq + Clone> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let node = self.current.take()?;
+
+        // We've made it all the way around the ring
+        if Rc::ptr_eq(&node, &self.list.sentinel) {
+            return None;
+        }
+
+        self.current = node.borrow().next.clone();
+        let data = node.borrow().data.clone();
+        data
+    }
+}
+
+/// Iterator over the data in a list, from back to front.
+///
+/// Created by `List::iter_rev`. Works the same as `Iter` but follows the `prev` links instead.
+pub struct IterRev<'a, T: PartialEq + Clone> {
+    list: &'a List<T>,
+    current: Link<T>,
+}
+
+impl<T: PartialEq + Clone> Iterator for IterRev<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let node = self.current.take()?;
+
+        // We've made it all the way around the ring
+        if Rc::ptr_eq(&node, &self.list.sentinel) {
+            return None;
+        }
+
+        self.current = node.borrow().prev_node();
+        let data = node.borrow().data.clone();
+        data
+    }
+}
+
+/// A position in a list that can move in both directions and edit the list around it.
+///
+/// Created by `List::cursor_front`. Besides pointing at an element, the cursor can point at the
+/// sentinel, which sits between the back and the front of the list. Moving past either end lands
+/// on the sentinel, and moving again wraps around to the other end.
+pub struct Cursor<'a, T: PartialEq + Clone> {
+    list: &'a mut List<T>,
+    current: Rc<RefCell<Node<T>>>,
+}
+
+impl<T: PartialEq + Clone> Cursor<'_, T> {
+    /// Moves the cursor to the next element, or onto the sentinel if it was on the last element.
+    pub fn move_next(&mut self) {
+        let next = self.current.borrow().next.clone().unwrap();
+        self.current = next;
+    }
+
+    /// Moves the cursor to the previous element, or onto the sentinel if it was on the first
+    /// element.
+    pub fn move_prev(&mut self) {
+        let prev = self.current.borrow().prev_node().unwrap();
+        self.current = prev;
+    }
+
+    /// Gets the element the cursor is pointing at.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is a clone of the current element.
+    /// 2. `None` if the cursor is on the sentinel.
+    pub fn current(&self) -> Option<T> {
+        if self.is_on_sentinel() {
+            None
+        } else {
+            self.current.borrow().data.clone()
+        }
+    }
+
+    /// Inserts data directly after the current element. If the cursor is on the sentinel the data
+    /// is added to the front of the list. The cursor doesn't move.
+    ///
+    /// ## Parameters
+    ///
+    /// - `data: T` is the data to insert.
+    pub fn insert_after(&mut self, data: T) {
+        self.list.insert_after_node(&self.current, data);
+    }
+
+    /// Removes the current element and moves the cursor to the element after it.
+    ///
+    /// ## Returns
+    ///
+    /// 1. `Some(data)` where `data` is the data that was removed.
+    /// 2. `None` if the cursor is on the sentinel, in which case nothing is removed.
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.is_on_sentinel() {
+            None
+        } else {
+            let next = self.current.borrow().next.clone().unwrap();
+            let data = self.list.unlink_node(&self.current);
+            self.current = next;
+            Some(data)
+        }
+    }
+
+    /// Determines if the cursor is on the sentinel rather than an element.
+    fn is_on_sentinel(&self) -> bool {
+        Rc::ptr_eq(&self.current, &self.list.sentinel)
+    }
+}
+
+/// Owning iterator over the data in a list, from front to back.
+///
+/// Created by `List::into_iter`. Each item is moved out of the list rather than cloned.
+pub struct IntoIter<T: PartialEq + Clone> {
+    list: List<T>,
+}
+
+impl<T: PartialEq + Clone> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.size, Some(self.list.size))
+    }
+}
+
+impl<T: PartialEq + Clone> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<T: PartialEq + Clone> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new_list();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: PartialEq + Clone> From<Vec<T>> for List<T> {
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T: PartialEq + Clone> From<VecDeque<T>> for List<T> {
+    fn from(deque: VecDeque<T>) -> Self {
+        deque.into_iter().collect()
+    }
+}
+
+impl<T: PartialEq + Clone> From<List<T>> for LinkedList<T> {
+    fn from(list: List<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T: PartialEq + Clone> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        // Add to the back so the new data ends up in the same order as the iterator
+        for data in iter {
+            self.push_back(data);
+        }
+    }
+}
+
+// Lists are serialized as a plain sequence of their data from front to back, the sentinel is left out
+#[cfg(feature = "serde")]
+impl<T: PartialEq + Clone + serde::Serialize> serde::Serialize for List<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: PartialEq + Clone + serde::Deserialize<'de>> serde::Deserialize<'de> for List<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data: Vec<T> = Vec::deserialize(deserializer)?;
+        Ok(List::from(data))
+    }
+}
+
+// Deriving Debug would dump the whole Rc graph, so only the data is printed
+impl<T: PartialEq + Clone + Debug> Debug for List<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("List")
+            .field("size", &self.size)
+            .field("elements", &self.to_vec())
+            .finish()
+    }
+}
+
+// Deriving Clone would only clone the Rc handles and share the nodes, so every node is copied into
+// a brand new ring instead
+impl<T: PartialEq + Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        let mut list = self.new_like();
+        list.extend(self.iter());
+        list
+    }
+}
+
+// Two lists are equal when they hold equal data in the same order, regardless of which nodes hold it
+impl<T: PartialEq + Clone> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq + Clone + Eq> Eq for List<T> {}
+
+// Lists are compared lexicographically, the same as `Vec`, so a list that runs out first is less
+impl<T: PartialEq + Clone + PartialOrd> PartialOrd for List<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: PartialEq + Clone + Ord> Ord for List<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+// Hashes the same things PartialEq compares so equal lists always hash the same
+impl<T: PartialEq + Clone + Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+        for data in self.iter() {
+            data.hash(state);
+        }
+    }
+}
+
+// Break the ring so the nodes are actually freed
+impl<T: PartialEq + Clone> Drop for List<T> {
+    fn drop(&mut self) {
+        self.unlink_all();
+    }
+}
+
+// This is Rust's version of toString
+impl<T: PartialEq + Clone + Display> Display for List<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.format_with(" -> ", true))
     }
 }
@@ -1,5 +1,7 @@
-use crate::lab::List;
-use std::rc::Rc;
+use crate::lab::{List, ListError};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet, LinkedList, VecDeque};
+use std::rc::{Rc, Weak};
 
 // Moved to separate file under recommendation from Michael Olacsi
 
@@ -8,14 +10,14 @@ fn test_create() {
     let list: List<usize> = List::new_list();
 
     assert_eq!(list.size, 0);
-    assert_eq!(list.sentinel.borrow().data, usize::default());
+    assert_eq!(list.sentinel.borrow().data, None);
     assert!(Rc::ptr_eq(
         &list.sentinel,
         &list.sentinel.borrow().next.clone().unwrap()
     ));
     assert!(Rc::ptr_eq(
         &list.sentinel,
-        &list.sentinel.borrow().prev.clone().unwrap()
+        &list.sentinel.borrow().prev_node().unwrap()
     ));
 }
 
@@ -30,7 +32,7 @@ fn test_add_one() {
     assert_eq!(list.size, 1);
 
     // Assert that sentinel node still exists
-    assert_eq!(list.sentinel.borrow().data, usize::default());
+    assert_eq!(list.sentinel.borrow().data, None);
 
     // Check that the sentinel node now points to the new node in the next member
     assert!(!Rc::ptr_eq(
@@ -45,7 +47,7 @@ fn test_add_one() {
     ));
 
     // Check that data is correct in the new node
-    assert_eq!(list.sentinel.borrow().data, 0);
+    assert_eq!(list.sentinel.borrow().data, None);
 
     // Check that the next and prev pointers are correct in the new node
     assert!(Rc::ptr_eq(
@@ -69,8 +71,7 @@ fn test_add_one() {
             .clone()
             .unwrap()
             .borrow()
-            .prev
-            .clone()
+            .prev_node()
             .unwrap(),
         &list.sentinel
     ));
@@ -83,14 +84,14 @@ fn test_remove_index_zero() {
     list.add(0);
     assert_eq!(list.remove_index(0), Ok(0));
     assert_eq!(list.size, 0);
-    assert_eq!(list.sentinel.borrow().data, usize::default());
+    assert_eq!(list.sentinel.borrow().data, None);
     assert!(Rc::ptr_eq(
         &list.sentinel,
         &list.sentinel.borrow().next.clone().unwrap()
     ));
     assert!(Rc::ptr_eq(
         &list.sentinel,
-        &list.sentinel.borrow().prev.clone().unwrap()
+        &list.sentinel.borrow().prev_node().unwrap()
     ));
 }
 
@@ -113,14 +114,14 @@ fn test_add_two() {
     assert_eq!(list.size, 2);
 
     // Assert that the nodes are in the proper order
-    assert_eq!(one_node.data, 1);
-    assert_eq!(zero_node.data, 0);
+    assert_eq!(one_node.data, Some(1));
+    assert_eq!(zero_node.data, Some(0));
     assert_eq!(sentinel.next.clone().unwrap().borrow().data, one_node.data);
-    assert_eq!(sentinel.prev.clone().unwrap().borrow().data, zero_node.data);
+    assert_eq!(sentinel.prev_node().unwrap().borrow().data, zero_node.data);
     assert_eq!(one_node.next.clone().unwrap().borrow().data, zero_node.data);
-    assert_eq!(one_node.prev.clone().unwrap().borrow().data, sentinel.data);
+    assert_eq!(one_node.prev_node().unwrap().borrow().data, sentinel.data);
     assert_eq!(zero_node.next.clone().unwrap().borrow().data, sentinel.data);
-    assert_eq!(zero_node.prev.clone().unwrap().borrow().data, one_node.data);
+    assert_eq!(zero_node.prev_node().unwrap().borrow().data, one_node.data);
 }
 
 #[test]
@@ -150,35 +151,32 @@ fn test_remove_index_three() {
 
     // Assert that all the nodes have the correct values (ensuring that the correct node was
     // removed)
-    assert_eq!(sentinel.data, usize::default());
-    assert_eq!(four_node.data, 4);
-    assert_eq!(three_node.data, 3);
-    assert_eq!(two_node.data, 2);
-    assert_eq!(zero_node.data, 0);
+    assert_eq!(sentinel.data, None);
+    assert_eq!(four_node.data, Some(4));
+    assert_eq!(three_node.data, Some(3));
+    assert_eq!(two_node.data, Some(2));
+    assert_eq!(zero_node.data, Some(0));
 
     // Assert that the order of nodes are correct
     assert_eq!(sentinel.next.clone().unwrap().borrow().data, four_node.data);
-    assert_eq!(sentinel.prev.clone().unwrap().borrow().data, zero_node.data);
+    assert_eq!(sentinel.prev_node().unwrap().borrow().data, zero_node.data);
     assert_eq!(
         four_node.next.clone().unwrap().borrow().data,
         three_node.data
     );
-    assert_eq!(four_node.prev.clone().unwrap().borrow().data, sentinel.data);
+    assert_eq!(four_node.prev_node().unwrap().borrow().data, sentinel.data);
     assert_eq!(
         three_node.next.clone().unwrap().borrow().data,
         two_node.data
     );
     assert_eq!(
-        three_node.prev.clone().unwrap().borrow().data,
+        three_node.prev_node().unwrap().borrow().data,
         four_node.data
     );
     assert_eq!(two_node.next.clone().unwrap().borrow().data, zero_node.data);
-    assert_eq!(
-        two_node.prev.clone().unwrap().borrow().data,
-        three_node.data
-    );
+    assert_eq!(two_node.prev_node().unwrap().borrow().data, three_node.data);
     assert_eq!(zero_node.next.clone().unwrap().borrow().data, sentinel.data);
-    assert_eq!(zero_node.prev.clone().unwrap().borrow().data, two_node.data);
+    assert_eq!(zero_node.prev_node().unwrap().borrow().data, two_node.data);
 }
 
 #[test]
@@ -208,34 +206,31 @@ fn test_remove_index_four() {
 
     // Assert that all the nodes have the correct values (ensuring that the correct node was
     // removed)
-    assert_eq!(sentinel.data, usize::default());
-    assert_eq!(four_node.data, 4);
-    assert_eq!(three_node.data, 3);
-    assert_eq!(two_node.data, 2);
-    assert_eq!(one_node.data, 1);
+    assert_eq!(sentinel.data, None);
+    assert_eq!(four_node.data, Some(4));
+    assert_eq!(three_node.data, Some(3));
+    assert_eq!(two_node.data, Some(2));
+    assert_eq!(one_node.data, Some(1));
 
     assert_eq!(sentinel.next.clone().unwrap().borrow().data, four_node.data);
-    assert_eq!(sentinel.prev.clone().unwrap().borrow().data, one_node.data);
+    assert_eq!(sentinel.prev_node().unwrap().borrow().data, one_node.data);
     assert_eq!(
         four_node.next.clone().unwrap().borrow().data,
         three_node.data
     );
-    assert_eq!(four_node.prev.clone().unwrap().borrow().data, sentinel.data);
+    assert_eq!(four_node.prev_node().unwrap().borrow().data, sentinel.data);
     assert_eq!(
         three_node.next.clone().unwrap().borrow().data,
         two_node.data
     );
     assert_eq!(
-        three_node.prev.clone().unwrap().borrow().data,
+        three_node.prev_node().unwrap().borrow().data,
         four_node.data
     );
     assert_eq!(two_node.next.clone().unwrap().borrow().data, one_node.data);
-    assert_eq!(
-        two_node.prev.clone().unwrap().borrow().data,
-        three_node.data
-    );
+    assert_eq!(two_node.prev_node().unwrap().borrow().data, three_node.data);
     assert_eq!(one_node.next.clone().unwrap().borrow().data, sentinel.data);
-    assert_eq!(one_node.prev.clone().unwrap().borrow().data, two_node.data);
+    assert_eq!(one_node.prev_node().unwrap().borrow().data, two_node.data);
 }
 
 #[test]
@@ -248,10 +243,7 @@ fn test_invalid_index() {
     // List should be 4 -> 3 -> 2 -> 1 -> 0 -> (sentinel)
 
     assert_eq!(list.size, 5);
-    assert_eq!(
-        list.remove_index(666),
-        Err(String::from("Index out of bounds"))
-    );
+    assert_eq!(list.remove_index(666), Err(ListError::IndexOutOfBounds));
     assert_eq!(list.size, 5);
 
     // List should still be 4 -> 3 -> 2 -> 1 -> 0 -> (sentinel)
@@ -269,38 +261,35 @@ fn test_invalid_index() {
     let zero_node = binding_five.borrow();
 
     // Assert that all the nodes have the correct values (ensuring that no node was removed)
-    assert_eq!(sentinel.data, usize::default());
-    assert_eq!(four_node.data, 4);
-    assert_eq!(three_node.data, 3);
-    assert_eq!(two_node.data, 2);
-    assert_eq!(one_node.data, 1);
-    assert_eq!(zero_node.data, 0);
+    assert_eq!(sentinel.data, None);
+    assert_eq!(four_node.data, Some(4));
+    assert_eq!(three_node.data, Some(3));
+    assert_eq!(two_node.data, Some(2));
+    assert_eq!(one_node.data, Some(1));
+    assert_eq!(zero_node.data, Some(0));
 
     // Assert that all the nodes are still in the correct order
     assert_eq!(sentinel.next.clone().unwrap().borrow().data, four_node.data);
-    assert_eq!(sentinel.prev.clone().unwrap().borrow().data, zero_node.data);
+    assert_eq!(sentinel.prev_node().unwrap().borrow().data, zero_node.data);
     assert_eq!(
         four_node.next.clone().unwrap().borrow().data,
         three_node.data
     );
-    assert_eq!(four_node.prev.clone().unwrap().borrow().data, sentinel.data);
+    assert_eq!(four_node.prev_node().unwrap().borrow().data, sentinel.data);
     assert_eq!(
         three_node.next.clone().unwrap().borrow().data,
         two_node.data
     );
     assert_eq!(
-        three_node.prev.clone().unwrap().borrow().data,
+        three_node.prev_node().unwrap().borrow().data,
         four_node.data
     );
     assert_eq!(two_node.next.clone().unwrap().borrow().data, one_node.data);
-    assert_eq!(
-        two_node.prev.clone().unwrap().borrow().data,
-        three_node.data
-    );
+    assert_eq!(two_node.prev_node().unwrap().borrow().data, three_node.data);
     assert_eq!(one_node.next.clone().unwrap().borrow().data, zero_node.data);
-    assert_eq!(one_node.prev.clone().unwrap().borrow().data, two_node.data);
+    assert_eq!(one_node.prev_node().unwrap().borrow().data, two_node.data);
     assert_eq!(zero_node.next.clone().unwrap().borrow().data, sentinel.data);
-    assert_eq!(zero_node.prev.clone().unwrap().borrow().data, one_node.data);
+    assert_eq!(zero_node.prev_node().unwrap().borrow().data, one_node.data);
 }
 
 #[test]
@@ -317,14 +306,14 @@ fn test_remove_all() {
 
     // Check that we're only left with the sentinel node
     assert_eq!(list.size, 0);
-    assert_eq!(list.sentinel.borrow().data, usize::default());
+    assert_eq!(list.sentinel.borrow().data, None);
     assert!(Rc::ptr_eq(
         &list.sentinel,
         &list.sentinel.borrow().next.clone().unwrap()
     ));
     assert!(Rc::ptr_eq(
         &list.sentinel,
-        &list.sentinel.borrow().prev.clone().unwrap()
+        &list.sentinel.borrow().prev_node().unwrap()
     ));
 }
 
@@ -366,7 +355,7 @@ fn test_is_empty_true() {
     let list: List<usize> = List::new_list();
 
     assert_eq!(list.size, 0);
-    assert_eq!(list.is_empty(), true);
+    assert!(list.is_empty());
 }
 
 #[test]
@@ -377,7 +366,7 @@ fn test_is_empty_false() {
     }
 
     assert_eq!(list.size, 5);
-    assert_eq!(list.is_empty(), false);
+    assert!(!list.is_empty());
 }
 
 #[test]
@@ -396,3 +385,2292 @@ fn test_display_with_elements() {
 
     assert_eq!(format!("{}", list), "4 -> 3 -> 2 -> 1 -> 0 -> (sentinel)\n");
 }
+
+#[test]
+fn test_get_zero() {
+    let mut list: List<usize> = List::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    // List should be 4 -> 3 -> 2 -> 1 -> 0 -> (sentinel)
+
+    assert_eq!(list.get(0), Some(4));
+}
+
+#[test]
+fn test_get_last() {
+    let mut list: List<usize> = List::new_list();
+    for i in 1..6 {
+        list.add(i);
+    }
+
+    // List should be 5 -> 4 -> 3 -> 2 -> 1 -> (sentinel)
+
+    assert_eq!(list.get(4), Some(1));
+}
+
+#[test]
+fn test_get_out_of_bounds() {
+    let mut list: List<usize> = List::new_list();
+    for i in 1..6 {
+        list.add(i);
+    }
+
+    // Index 5 would wrap around to the sentinel, make sure we don't hand back its data
+    assert_eq!(list.get(5), None);
+    assert_eq!(list.get(666), None);
+}
+
+#[test]
+fn test_add_maintains_tail() {
+    let mut list: List<usize> = List::new_list();
+    for i in 1..4 {
+        list.add(i);
+    }
+
+    // List should be 3 -> 2 -> 1 -> (sentinel)
+
+    let sentinel = list.sentinel.borrow();
+    let binding_one = sentinel.next.clone().unwrap();
+    let three_node = binding_one.borrow();
+    let binding_two = three_node.next.clone().unwrap();
+    let two_node = binding_two.borrow();
+    let one_node = two_node.next.clone().unwrap();
+
+    // The tail holds Some(1) rather than the sentinel's None, so this can't pass by accident
+    assert_eq!(one_node.borrow().data, Some(1));
+    assert!(Rc::ptr_eq(&sentinel.prev_node().unwrap(), &one_node));
+    assert!(Rc::ptr_eq(
+        &one_node.borrow().next.clone().unwrap(),
+        &list.sentinel
+    ));
+    assert_eq!(
+        one_node.borrow().prev_node().unwrap().borrow().data,
+        Some(2)
+    );
+}
+
+#[test]
+fn test_drop_frees_nodes() {
+    let mut list: List<usize> = List::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    // Keep weak handles on the sentinel and every node so we can see if they were freed
+    let sentinel: Weak<_> = Rc::downgrade(&list.sentinel);
+    let mut nodes: Vec<Weak<_>> = Vec::new();
+    let mut current = list.sentinel.borrow().next.clone();
+    for _ in 0..list.size {
+        let node = current.unwrap();
+        nodes.push(Rc::downgrade(&node));
+        current = node.borrow().next.clone();
+    }
+    drop(current);
+
+    drop(list);
+
+    assert!(sentinel.upgrade().is_none());
+    for node in nodes {
+        assert_eq!(node.strong_count(), 0);
+    }
+}
+
+#[test]
+fn test_push_back_empty() {
+    let mut list: List<usize> = List::new_list();
+
+    list.push_back(7);
+
+    assert_eq!(list.size, 1);
+    assert_eq!(format!("{}", list), "7 -> (sentinel)\n");
+    assert!(Rc::ptr_eq(
+        &list.sentinel.borrow().next.clone().unwrap(),
+        &list.sentinel.borrow().prev_node().unwrap()
+    ));
+}
+
+#[test]
+fn test_push_back_after_add() {
+    let mut list: List<usize> = List::new_list();
+
+    list.add(1);
+    list.push_back(2);
+
+    assert_eq!(list.size, 2);
+    assert_eq!(format!("{}", list), "1 -> 2 -> (sentinel)\n");
+}
+
+#[test]
+fn test_push_back_many() {
+    let mut list: List<usize> = List::new_list();
+    for i in 0..5 {
+        list.push_back(i);
+    }
+
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+    assert_eq!(
+        list.sentinel.borrow().prev_node().unwrap().borrow().data,
+        Some(4)
+    );
+}
+
+#[test]
+fn test_pop_front_empty() {
+    let mut list: List<usize> = List::new_list();
+
+    assert_eq!(list.pop_front(), None);
+    assert_eq!(list.size, 0);
+}
+
+#[test]
+fn test_pop_front_one() {
+    let mut list: List<usize> = List::new_list();
+
+    list.add(3);
+    assert_eq!(list.pop_front(), Some(3));
+    assert_eq!(list.size, 0);
+
+    // Check that we're only left with the sentinel node
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().next.clone().unwrap()
+    ));
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().prev_node().unwrap()
+    ));
+}
+
+#[test]
+fn test_pop_front_many() {
+    let mut list: List<usize> = List::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    // List should be 4 -> 3 -> 2 -> 1 -> 0 -> (sentinel)
+
+    for i in 0..5 {
+        assert_eq!(list.pop_front(), Some(4 - i));
+        assert_eq!(list.size, 4 - i);
+    }
+    assert_eq!(list.pop_front(), None);
+    assert_eq!(format!("{}", list), "(sentinel)\n");
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().prev_node().unwrap()
+    ));
+}
+
+#[test]
+fn test_pop_back_empty() {
+    let mut list: List<usize> = List::new_list();
+
+    assert_eq!(list.pop_back(), None);
+    assert_eq!(list.size, 0);
+}
+
+#[test]
+fn test_pop_back_one() {
+    let mut list: List<usize> = List::new_list();
+
+    list.add(3);
+    assert_eq!(list.pop_back(), Some(3));
+    assert_eq!(list.size, 0);
+
+    // Check that we're only left with the sentinel node
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().next.clone().unwrap()
+    ));
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().prev_node().unwrap()
+    ));
+}
+
+#[test]
+fn test_pop_back_many() {
+    let mut list: List<usize> = List::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    // List should be 4 -> 3 -> 2 -> 1 -> 0 -> (sentinel)
+
+    assert_eq!(list.pop_back(), Some(0));
+    assert_eq!(list.size, 4);
+    assert_eq!(list.pop_back(), Some(1));
+    assert_eq!(list.size, 3);
+    assert_eq!(format!("{}", list), "4 -> 3 -> 2 -> (sentinel)\n");
+    assert_eq!(
+        list.sentinel.borrow().prev_node().unwrap().borrow().data,
+        Some(2)
+    );
+
+    for i in 0..3 {
+        assert_eq!(list.pop_back(), Some(2 + i));
+        assert_eq!(list.size, 2 - i);
+    }
+    assert_eq!(list.pop_back(), None);
+    assert_eq!(format!("{}", list), "(sentinel)\n");
+}
+
+#[test]
+fn test_peek_empty() {
+    let list: List<usize> = List::new_list();
+
+    assert_eq!(list.peek_front(), None);
+    assert_eq!(list.peek_back(), None);
+}
+
+#[test]
+fn test_peek_front_and_back() {
+    let mut list: List<usize> = List::new_list();
+    for i in 1..4 {
+        list.add(i);
+    }
+
+    // List should be 3 -> 2 -> 1 -> (sentinel)
+
+    assert_eq!(list.peek_front(), Some(3));
+    assert_eq!(list.peek_back(), Some(1));
+
+    // Peeking shouldn't remove anything
+    assert_eq!(list.size, 3);
+    assert_eq!(format!("{}", list), "3 -> 2 -> 1 -> (sentinel)\n");
+}
+
+#[test]
+fn test_iter_empty() {
+    let list: List<usize> = List::new_list();
+
+    assert_eq!(list.iter().collect::<Vec<usize>>(), Vec::<usize>::new());
+}
+
+#[test]
+fn test_iter_order() {
+    let mut list: List<usize> = List::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    // List should be 4 -> 3 -> 2 -> 1 -> 0 -> (sentinel)
+
+    assert_eq!(list.iter().collect::<Vec<usize>>(), vec![4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn test_iter_adapters() {
+    let mut list: List<usize> = List::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    let mut total = 0;
+    for data in list.iter() {
+        total += data;
+    }
+    assert_eq!(total, 10);
+
+    let doubled_evens: Vec<usize> = list.iter().filter(|x| x % 2 == 0).map(|x| x * 2).collect();
+    assert_eq!(doubled_evens, vec![8, 4, 0]);
+}
+
+#[test]
+fn test_iter_rev() {
+    for n in [0, 1, 5] {
+        let mut list: List<usize> = List::new_list();
+        for i in 0..n {
+            list.add(i);
+        }
+
+        let mut forward: Vec<usize> = list.iter().collect();
+        forward.reverse();
+
+        assert_eq!(list.iter_rev().collect::<Vec<usize>>(), forward);
+    }
+}
+
+#[test]
+fn test_into_iter() {
+    let mut list: List<usize> = List::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    // List should be 4 -> 3 -> 2 -> 1 -> 0 -> (sentinel)
+
+    let mut owned: Vec<usize> = Vec::new();
+    for data in list {
+        owned.push(data);
+    }
+
+    assert_eq!(owned, vec![4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn test_into_iter_frees_nodes() {
+    let mut list: List<String> = List::new_list();
+    for word in ["c", "b", "a"] {
+        list.add(String::from(word));
+    }
+
+    let sentinel: Weak<_> = Rc::downgrade(&list.sentinel);
+    let mut iter = list.into_iter();
+
+    // Stop partway through, the rest of the list should still be freed
+    assert_eq!(iter.next(), Some(String::from("a")));
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    drop(iter);
+
+    assert!(sentinel.upgrade().is_none());
+}
+
+#[test]
+fn test_from_iter() {
+    let list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+}
+
+#[test]
+fn test_from_iter_empty() {
+    let list: List<i32> = Vec::new().into_iter().collect();
+
+    assert!(list.is_empty());
+    assert_eq!(format!("{}", list), "(sentinel)\n");
+}
+
+#[test]
+fn test_extend() {
+    let mut list: List<usize> = (1..4).collect();
+
+    list.extend(vec![4, 5, 6]);
+
+    assert_eq!(list.size, 6);
+    assert_eq!(
+        format!("{}", list),
+        "1 -> 2 -> 3 -> 4 -> 5 -> 6 -> (sentinel)\n"
+    );
+}
+
+#[test]
+fn test_clear() {
+    let mut list: List<usize> = List::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    let first: Weak<_> = Rc::downgrade(&list.sentinel.borrow().next.clone().unwrap());
+
+    list.clear();
+
+    // Check that we're only left with the sentinel node
+    assert!(list.is_empty());
+    assert_eq!(list.size, 0);
+    assert_eq!(list.sentinel.borrow().data, None);
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().next.clone().unwrap()
+    ));
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().prev_node().unwrap()
+    ));
+    assert!(first.upgrade().is_none());
+
+    // The list should still be usable afterwards
+    list.add(1);
+    assert_eq!(format!("{}", list), "1 -> (sentinel)\n");
+}
+
+#[test]
+fn test_insert_front() {
+    let mut list: List<usize> = (1..4).collect();
+
+    assert_eq!(list.insert(0, 0), Ok(()));
+    assert_eq!(list.size, 4);
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> (sentinel)\n");
+}
+
+#[test]
+fn test_insert_middle() {
+    let mut list: List<usize> = (1..4).collect();
+
+    assert_eq!(list.insert(2, 9), Ok(()));
+    assert_eq!(list.size, 4);
+    assert_eq!(format!("{}", list), "1 -> 2 -> 9 -> 3 -> (sentinel)\n");
+}
+
+#[test]
+fn test_insert_tail() {
+    let mut list: List<usize> = (1..4).collect();
+
+    assert_eq!(list.insert(3, 4), Ok(()));
+    assert_eq!(list.size, 4);
+    assert_eq!(format!("{}", list), "1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+    assert_eq!(list.peek_back(), Some(4));
+}
+
+#[test]
+fn test_insert_invalid_index() {
+    let mut list: List<usize> = (1..4).collect();
+
+    assert_eq!(list.insert(4, 4), Err(ListError::IndexOutOfBounds));
+    assert_eq!(list.size, 3);
+    assert_eq!(format!("{}", list), "1 -> 2 -> 3 -> (sentinel)\n");
+}
+
+#[test]
+fn test_remove_value_middle() {
+    let mut list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.remove_value(&2), Some(2));
+    assert_eq!(list.size, 4);
+    assert_eq!(format!("{}", list), "0 -> 1 -> 3 -> 4 -> (sentinel)\n");
+
+    // Check the links on both sides of the removed node
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![4, 3, 1, 0]);
+}
+
+#[test]
+fn test_remove_value_tail() {
+    let mut list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.remove_value(&4), Some(4));
+    assert_eq!(list.size, 4);
+    assert_eq!(list.peek_back(), Some(3));
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> (sentinel)\n");
+}
+
+#[test]
+fn test_remove_value_not_in_list() {
+    let mut list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.remove_value(&22), None);
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+}
+
+#[test]
+fn test_clone_is_independent() {
+    let list: List<usize> = (0..5).collect();
+    let mut copy = list.clone();
+
+    assert_eq!(copy.size, 5);
+    assert_eq!(format!("{}", copy), format!("{}", list));
+    assert!(!Rc::ptr_eq(&list.sentinel, &copy.sentinel));
+
+    assert_eq!(copy.remove_index(2), Ok(2));
+
+    // The original should be untouched
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+    assert_eq!(format!("{}", copy), "0 -> 1 -> 3 -> 4 -> (sentinel)\n");
+}
+
+#[test]
+fn test_eq_built_independently() {
+    let mut list_a: List<usize> = List::new_list();
+    for i in (0..5).rev() {
+        list_a.add(i);
+    }
+    let list_b: List<usize> = (0..5).collect();
+
+    assert_eq!(list_a, list_b);
+    assert_eq!(List::<usize>::new_list(), List::<usize>::new_list());
+}
+
+#[test]
+fn test_eq_different_lengths() {
+    let list_a: List<usize> = (0..5).collect();
+    let list_b: List<usize> = (0..4).collect();
+
+    assert_ne!(list_a, list_b);
+    assert_ne!(list_b, list_a);
+}
+
+#[test]
+fn test_eq_different_order() {
+    let list_a: List<usize> = (0..5).collect();
+    let list_b: List<usize> = (0..5).rev().collect();
+
+    assert_ne!(list_a, list_b);
+}
+
+#[test]
+fn test_len() {
+    let mut list: List<usize> = List::new_list();
+    assert_eq!(list.len(), 0);
+
+    for i in 0..5 {
+        list.add(i);
+        assert_eq!(list.len(), i + 1);
+    }
+
+    assert_eq!(list.remove_index(2), Ok(2));
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.remove_index(666), Err(ListError::IndexOutOfBounds));
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.len(), list.size);
+}
+
+#[test]
+fn test_get_outlives_mutation() {
+    let mut list: List<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+
+    // get hands back its own copy, so overwriting the node through a handle can't invalidate it
+    let first = list.get(0).unwrap();
+    list.get_node_ref(0).unwrap().borrow_mut().data = Some("x".repeat(64));
+    assert_eq!(first, "a");
+    assert_eq!(list.get(0), Some("x".repeat(64)));
+
+    let second = list.get(1).unwrap();
+    list.update(1, String::from("c")).unwrap();
+    assert_eq!(second, "b");
+}
+
+#[test]
+fn test_to_vec_empty() {
+    let list: List<usize> = List::new_list();
+
+    assert_eq!(list.to_vec(), Vec::<usize>::new());
+}
+
+#[test]
+fn test_to_vec() {
+    let mut list: List<usize> = List::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    assert_eq!(list.to_vec(), vec![4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn test_from_vec() {
+    let v: Vec<usize> = vec![1, 2, 3];
+    let list = List::from(v.clone());
+
+    assert_eq!(list.size, 3);
+    assert_eq!(list.peek_front(), Some(1));
+    assert_eq!(list.peek_back(), Some(3));
+    assert_eq!(list.to_vec(), v);
+}
+
+#[test]
+fn test_reverse() {
+    let mut list: List<usize> = List::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    assert_eq!(format!("{}", list), "4 -> 3 -> 2 -> 1 -> 0 -> (sentinel)\n");
+    list.reverse();
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+    assert_eq!(list.size, 5);
+
+    // The backward links should have been reversed too
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn test_reverse_empty_and_single() {
+    let mut list: List<usize> = List::new_list();
+
+    list.reverse();
+    assert_eq!(format!("{}", list), "(sentinel)\n");
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().next.clone().unwrap()
+    ));
+
+    list.add(7);
+    list.reverse();
+    assert_eq!(format!("{}", list), "7 -> (sentinel)\n");
+    assert_eq!(list.size, 1);
+}
+
+#[test]
+fn test_sort() {
+    let mut list: List<usize> = List::from(vec![3, 0, 4, 1, 2]);
+
+    list.sort();
+
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn test_sort_empty() {
+    let mut list: List<usize> = List::new_list();
+
+    list.sort();
+
+    assert_eq!(format!("{}", list), "(sentinel)\n");
+}
+
+#[test]
+fn test_sort_by_length_descending() {
+    let mut list: List<String> = ["bb", "a", "dddd", "ccc"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+    list.sort_by(|a, b| b.len().cmp(&a.len()));
+
+    assert_eq!(
+        format!("{}", list),
+        "dddd -> ccc -> bb -> a -> (sentinel)\n"
+    );
+}
+
+#[test]
+fn test_sort_by_single() {
+    let mut list: List<usize> = List::from(vec![1]);
+
+    list.sort_by(|a, b| b.cmp(a));
+
+    assert_eq!(format!("{}", list), "1 -> (sentinel)\n");
+}
+
+#[test]
+fn test_retain_even() {
+    let mut list: List<usize> = (0..10).collect();
+
+    list.retain(|x| x % 2 == 0);
+
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "0 -> 2 -> 4 -> 6 -> 8 -> (sentinel)\n");
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![8, 6, 4, 2, 0]);
+}
+
+#[test]
+fn test_retain_head_tail_and_consecutive() {
+    let mut list: List<usize> = List::from(vec![1, 1, 2, 1, 1, 3, 1]);
+
+    list.retain(|x| *x != 1);
+
+    assert_eq!(list.size, 2);
+    assert_eq!(list.to_vec(), vec![2, 3]);
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![3, 2]);
+}
+
+#[test]
+fn test_retain_nothing() {
+    let mut list: List<usize> = (0..10).collect();
+
+    list.retain(|_| false);
+
+    // Check that we're only left with the sentinel node
+    assert!(list.is_empty());
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().next.clone().unwrap()
+    ));
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().prev_node().unwrap()
+    ));
+}
+
+#[test]
+fn test_dedup() {
+    let mut list: List<usize> = List::from(vec![1, 1, 2, 3, 3, 3, 1]);
+
+    list.dedup();
+
+    assert_eq!(list.size, 4);
+    assert_eq!(list.to_vec(), vec![1, 2, 3, 1]);
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![1, 3, 2, 1]);
+}
+
+#[test]
+fn test_dedup_all_same() {
+    let mut list: List<usize> = List::from(vec![5, 5, 5]);
+
+    list.dedup();
+
+    assert_eq!(format!("{}", list), "5 -> (sentinel)\n");
+    assert_eq!(list.peek_back(), Some(5));
+}
+
+#[test]
+fn test_append() {
+    let mut list: List<usize> = List::from(vec![0, 1]);
+    let mut other: List<usize> = List::from(vec![2, 3, 4]);
+
+    list.append(&mut other);
+
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![4, 3, 2, 1, 0]);
+
+    // Check that other is only left with its sentinel node
+    assert_eq!(other.size, 0);
+    assert_eq!(format!("{}", other), "(sentinel)\n");
+    assert!(Rc::ptr_eq(
+        &other.sentinel,
+        &other.sentinel.borrow().next.clone().unwrap()
+    ));
+    assert!(Rc::ptr_eq(
+        &other.sentinel,
+        &other.sentinel.borrow().prev_node().unwrap()
+    ));
+}
+
+#[test]
+fn test_append_to_empty() {
+    let mut list: List<usize> = List::new_list();
+    let mut other: List<usize> = List::from(vec![1, 2]);
+
+    list.append(&mut other);
+    assert_eq!(list.to_vec(), vec![1, 2]);
+    assert!(other.is_empty());
+
+    // Appending an empty list shouldn't change anything
+    list.append(&mut other);
+    assert_eq!(list.to_vec(), vec![1, 2]);
+    assert_eq!(list.peek_back(), Some(2));
+}
+
+#[test]
+fn test_rotate_left() {
+    let mut list: List<usize> = (0..5).collect();
+
+    list.rotate_left(2);
+
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "2 -> 3 -> 4 -> 0 -> 1 -> (sentinel)\n");
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![1, 0, 4, 3, 2]);
+}
+
+#[test]
+fn test_rotate_left_by_size() {
+    let mut list: List<usize> = (0..5).collect();
+
+    list.rotate_left(5);
+    assert_eq!(list.to_vec(), vec![0, 1, 2, 3, 4]);
+
+    list.rotate_left(0);
+    assert_eq!(list.to_vec(), vec![0, 1, 2, 3, 4]);
+
+    list.rotate_left(7);
+    assert_eq!(list.to_vec(), vec![2, 3, 4, 0, 1]);
+}
+
+#[test]
+fn test_rotate_left_empty() {
+    let mut list: List<usize> = List::new_list();
+
+    list.rotate_left(3);
+
+    assert_eq!(format!("{}", list), "(sentinel)\n");
+}
+
+#[test]
+fn test_front_and_back_empty() {
+    let list: List<usize> = List::new_list();
+
+    assert_eq!(list.front(), None);
+    assert_eq!(list.back(), None);
+}
+
+#[test]
+fn test_front_and_back_single() {
+    let list: List<usize> = List::from(vec![8]);
+
+    assert_eq!(list.front(), Some(8));
+    assert_eq!(list.front(), list.back());
+}
+
+#[test]
+fn test_front_and_back_many() {
+    let list: List<usize> = (1..6).collect();
+
+    assert_eq!(list.front(), Some(1));
+    assert_eq!(list.back(), Some(5));
+}
+
+#[test]
+fn test_count() {
+    let list: List<usize> = List::from(vec![1, 2, 1, 3, 1]);
+
+    assert_eq!(list.count(&1), 3);
+    assert_eq!(list.count(&3), 1);
+    assert_eq!(list.count(&22), 0);
+}
+
+#[test]
+fn test_count_empty() {
+    let list: List<usize> = List::new_list();
+
+    assert_eq!(list.count(&0), 0);
+}
+
+#[test]
+fn test_format_with_comma() {
+    let mut list: List<usize> = List::new_list();
+    for i in 0..5 {
+        list.add(i);
+    }
+
+    assert_eq!(list.format_with(",", false), "4,3,2,1,0");
+    assert_eq!(list.format_with(", ", true), "4, 3, 2, 1, 0, (sentinel)\n");
+}
+
+#[test]
+fn test_format_with_empty() {
+    let list: List<usize> = List::new_list();
+
+    assert_eq!(list.format_with(",", false), "");
+    assert_eq!(list.format_with(",", true), "(sentinel)\n");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    let list: List<usize> = (0..5).collect();
+
+    let json = serde_json::to_string(&list).unwrap();
+    assert_eq!(json, "[0,1,2,3,4]");
+
+    let back: List<usize> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, list);
+    assert_eq!(back.peek_back(), Some(4));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_empty() {
+    let list: List<usize> = serde_json::from_str("[]").unwrap();
+
+    assert!(list.is_empty());
+    assert_eq!(serde_json::to_string(&list).unwrap(), "[]");
+}
+
+#[test]
+fn test_debug() {
+    let list: List<usize> = (1..4).collect();
+
+    assert_eq!(
+        format!("{:?}", list),
+        "List { size: 3, elements: [1, 2, 3] }"
+    );
+}
+
+#[test]
+fn test_debug_empty() {
+    let list: List<String> = List::new_list();
+
+    assert_eq!(format!("{:?}", list), "List { size: 0, elements: [] }");
+}
+
+#[test]
+fn test_find() {
+    let list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.find(|x| *x > 2), Some(3));
+}
+
+#[test]
+fn test_find_no_match() {
+    let list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.find(|x| *x > 22), None);
+
+    // The predicate should never be handed the sentinel's data
+    let mut checked = 0;
+    let empty: List<usize> = List::new_list();
+    assert_eq!(
+        empty.find(|_| {
+            checked += 1;
+            true
+        }),
+        None
+    );
+    assert_eq!(checked, 0);
+}
+
+#[test]
+fn test_position() {
+    let list: List<usize> = (0..5).map(|i| i * 10).collect();
+
+    assert_eq!(list.position(|x| *x >= 25), Some(3));
+    assert_eq!(list.position(|x| *x == 0), Some(0));
+}
+
+#[test]
+fn test_position_no_match() {
+    let list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.position(|x| *x > 22), None);
+}
+
+#[test]
+fn test_remove_index_empty() {
+    let mut list: List<usize> = List::new_list();
+
+    assert_eq!(list.remove_index(0), Err(ListError::Empty));
+    assert_eq!(list.size, 0);
+}
+
+#[test]
+fn test_list_error_display() {
+    assert_eq!(
+        ListError::Empty.to_string(),
+        "The list is empty, nothing was done"
+    );
+    assert_eq!(
+        ListError::IndexOutOfBounds.to_string(),
+        "Index out of bounds"
+    );
+    assert_eq!(
+        ListError::NotFound.to_string(),
+        "The data couldn't be found, nothing was done"
+    );
+    assert_eq!(
+        ListError::Corrupt("a next link is missing").to_string(),
+        "The list is corrupt: a next link is missing"
+    );
+    assert_eq!(
+        ListError::InvalidRange.to_string(),
+        "The range starts after it ends"
+    );
+    assert_eq!(
+        ListError::DuplicateIndex.to_string(),
+        "An index was given more than once"
+    );
+}
+
+#[test]
+fn test_update_head() {
+    let mut list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.update(0, 9), Ok(0));
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "9 -> 1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+}
+
+#[test]
+fn test_update_tail() {
+    let mut list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.update(4, 9), Ok(4));
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> 9 -> (sentinel)\n");
+    assert_eq!(list.peek_back(), Some(9));
+}
+
+#[test]
+fn test_update_invalid_index() {
+    let mut list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.update(5, 9), Err(ListError::IndexOutOfBounds));
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+}
+
+#[test]
+fn test_with_elements() {
+    let list: List<usize> = List::with_elements(&[1, 2, 3]);
+
+    assert_eq!(list.size, 3);
+    assert_eq!(list.to_vec(), vec![1, 2, 3]);
+    assert!(List::<usize>::with_elements(&[]).is_empty());
+}
+
+#[test]
+fn test_map() {
+    let list: List<usize> = (0..3).collect();
+
+    let strings: List<String> = list.map(|x| x.to_string());
+
+    assert_eq!(strings.size, 3);
+    assert_eq!(format!("{}", strings), format!("{}", list));
+    assert_eq!(strings.peek_front(), Some(String::from("0")));
+
+    let doubled = list.map(|x| x * 2);
+    assert_eq!(format!("{}", doubled), "0 -> 2 -> 4 -> (sentinel)\n");
+}
+
+#[test]
+fn test_fold_sum() {
+    let list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.fold(0, |acc, x| acc + x), 10);
+    assert_eq!(
+        list.fold(String::new(), |acc, x| format!("{}{}", acc, x)),
+        "01234"
+    );
+}
+
+#[test]
+fn test_fold_empty() {
+    let list: List<usize> = List::new_list();
+
+    assert_eq!(list.fold(7, |acc, x| acc + x), 7);
+}
+
+#[test]
+fn test_remove_all_value() {
+    let mut list: List<usize> = List::from(vec![1, 2, 1, 1, 3]);
+
+    assert_eq!(list.remove_all(&1), 3);
+    assert_eq!(list.size, 2);
+    assert_eq!(list.to_vec(), vec![2, 3]);
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![3, 2]);
+    assert_eq!(list.remove_all(&1), 0);
+}
+
+#[test]
+fn test_remove_all_value_everything() {
+    let mut list: List<usize> = List::from(vec![4, 4, 4]);
+
+    assert_eq!(list.remove_all(&4), 3);
+
+    // Check that we're only left with the sentinel node
+    assert!(list.is_empty());
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().next.clone().unwrap()
+    ));
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().prev_node().unwrap()
+    ));
+}
+
+#[test]
+fn test_truncate() {
+    let mut list: List<usize> = (0..5).collect();
+    let dropped: Weak<_> = Rc::downgrade(&list.sentinel.borrow().prev_node().unwrap());
+
+    list.truncate(2);
+
+    assert_eq!(list.size, 2);
+    assert_eq!(format!("{}", list), "0 -> 1 -> (sentinel)\n");
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![1, 0]);
+    assert!(dropped.upgrade().is_none());
+}
+
+#[test]
+fn test_truncate_longer_than_list() {
+    let mut list: List<usize> = (0..5).collect();
+
+    list.truncate(10);
+
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+}
+
+#[test]
+fn test_truncate_to_zero() {
+    let mut list: List<usize> = (0..5).collect();
+
+    list.truncate(0);
+
+    // Check that we're only left with the sentinel node
+    assert!(list.is_empty());
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().next.clone().unwrap()
+    ));
+    assert!(Rc::ptr_eq(
+        &list.sentinel,
+        &list.sentinel.borrow().prev_node().unwrap()
+    ));
+}
+
+#[test]
+fn test_cmp_index_of() {
+    let mut list: List<String> =
+        List::new_list_with_cmp(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+    for word in ["Cherry", "banana", "APPLE"] {
+        list.add(String::from(word));
+    }
+
+    // List should be APPLE -> banana -> Cherry -> (sentinel)
+
+    assert_eq!(list.index_of(String::from("apple")), Some(0));
+    assert_eq!(list.index_of(String::from("CHERRY")), Some(2));
+    assert!(list.contains(&String::from("Banana")));
+    assert!(!list.contains(&String::from("grape")));
+    assert_eq!(list.remove_value(&String::from("bAnAnA")), Some(1));
+    assert_eq!(format!("{}", list), "APPLE -> Cherry -> (sentinel)\n");
+
+    // Without a comparator the case has to match exactly
+    let plain: List<String> = list.iter().collect();
+    assert_eq!(plain.index_of(String::from("apple")), None);
+    assert!(plain.contains(&String::from("APPLE")));
+}
+
+#[test]
+fn test_cmp_count_remove_all_and_dedup() {
+    let mut list: List<String> =
+        List::new_list_with_cmp(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+    for word in ["b", "B", "a", "A", "a"] {
+        list.push_back(String::from(word));
+    }
+
+    assert_eq!(list.count(&String::from("A")), 3);
+    assert!(list.contains(&String::from("A")));
+
+    list.dedup();
+    assert_eq!(format!("{}", list), "b -> a -> (sentinel)\n");
+
+    list.push_back(String::from("B"));
+    assert_eq!(list.remove_all(&String::from("b")), 2);
+    assert_eq!(list.to_vec(), vec![String::from("a")]);
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn test_cmp_sort() {
+    let mut list: List<String> =
+        List::new_list_with_cmp(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+    for word in ["b", "C", "a"] {
+        list.add(String::from(word));
+    }
+
+    list.sort();
+    assert_eq!(format!("{}", list), "a -> b -> C -> (sentinel)\n");
+
+    // Clones keep the comparator
+    let copy = list.clone();
+    assert_eq!(copy.index_of(String::from("c")), Some(2));
+}
+
+#[test]
+fn test_into_linked_list() {
+    let list: List<usize> = List::from(vec![3, 1, 4, 1, 5]);
+    let expected = list.to_vec();
+
+    let std_list: LinkedList<usize> = list.into();
+
+    assert_eq!(std_list.len(), 5);
+    assert_eq!(std_list.into_iter().collect::<Vec<usize>>(), expected);
+}
+
+#[test]
+fn test_from_vec_deque() {
+    let mut deque: VecDeque<usize> = (1..5).collect();
+    deque.push_front(0);
+
+    let list = List::from(deque);
+
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+}
+
+// The nodes sit behind a RefCell, but the contents can only change through `&mut self` methods
+#[allow(clippy::mutable_key_type)]
+#[test]
+fn test_hash_equal_lists() {
+    let mut list_a: List<usize> = List::new_list();
+    for i in (0..5).rev() {
+        list_a.add(i);
+    }
+    let list_b: List<usize> = (0..5).collect();
+
+    let mut set = HashSet::new();
+    set.insert(list_a);
+    set.insert(list_b);
+
+    assert_eq!(set.len(), 1);
+
+    set.insert((0..4).collect());
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_prev_links_are_weak() {
+    let list: List<usize> = (0..5).collect();
+
+    // Each node should only be owned by the node before it
+    let mut current = list.sentinel.borrow().next.clone();
+    for _ in 0..list.size {
+        let node = current.unwrap();
+
+        // One for the previous node's next link, one for the local handle
+        assert_eq!(Rc::strong_count(&node), 2);
+        assert_eq!(Rc::weak_count(&node), 1);
+
+        current = node.borrow().next.clone();
+    }
+}
+
+#[test]
+fn test_for_each_mut() {
+    let mut list: List<usize> = (0..3).collect();
+
+    list.for_each_mut(|x| *x += 1);
+
+    assert_eq!(format!("{}", list), "1 -> 2 -> 3 -> (sentinel)\n");
+    assert_eq!(list.sentinel.borrow().data, None);
+}
+
+#[test]
+fn test_insert_after_value_middle() {
+    let mut list: List<usize> = List::from(vec![1, 2, 3]);
+
+    assert_eq!(list.insert_after_value(&2, 9), Ok(()));
+    assert_eq!(list.size, 4);
+    assert_eq!(format!("{}", list), "1 -> 2 -> 9 -> 3 -> (sentinel)\n");
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![3, 9, 2, 1]);
+}
+
+#[test]
+fn test_insert_after_value_tail() {
+    let mut list: List<usize> = List::from(vec![1, 2, 3]);
+
+    assert_eq!(list.insert_after_value(&3, 4), Ok(()));
+    assert_eq!(list.back(), Some(4));
+    assert_eq!(format!("{}", list), "1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+}
+
+#[test]
+fn test_insert_after_value_missing() {
+    let mut list: List<usize> = List::from(vec![1, 2, 3]);
+
+    assert_eq!(list.insert_after_value(&22, 9), Err(ListError::NotFound));
+    assert_eq!(list.size, 3);
+    assert_eq!(format!("{}", list), "1 -> 2 -> 3 -> (sentinel)\n");
+}
+
+#[test]
+fn test_insert_before_value_head() {
+    let mut list: List<usize> = List::from(vec![1, 2, 3]);
+
+    assert_eq!(list.insert_before_value(&1, 0), Ok(()));
+    assert_eq!(list.size, 4);
+    assert_eq!(list.front(), Some(0));
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> (sentinel)\n");
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![3, 2, 1, 0]);
+}
+
+#[test]
+fn test_insert_before_value_middle() {
+    let mut list: List<usize> = List::from(vec![1, 2, 3]);
+
+    assert_eq!(list.insert_before_value(&3, 9), Ok(()));
+    assert_eq!(format!("{}", list), "1 -> 2 -> 9 -> 3 -> (sentinel)\n");
+}
+
+#[test]
+fn test_insert_before_value_missing() {
+    let mut list: List<usize> = List::from(vec![1, 2, 3]);
+
+    assert_eq!(list.insert_before_value(&22, 9), Err(ListError::NotFound));
+    assert_eq!(list.size, 3);
+    assert_eq!(format!("{}", list), "1 -> 2 -> 3 -> (sentinel)\n");
+}
+
+#[test]
+fn test_cursor_move() {
+    let mut list: List<usize> = (0..3).collect();
+    let mut cursor = list.cursor_front();
+
+    assert_eq!(cursor.current(), Some(0));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(2));
+
+    // Moving past the back lands on the sentinel, then wraps to the front
+    cursor.move_next();
+    assert_eq!(cursor.current(), None);
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(0));
+
+    // And the same going backwards
+    cursor.move_prev();
+    assert_eq!(cursor.current(), None);
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(2));
+}
+
+#[test]
+fn test_cursor_insert_after() {
+    let mut list: List<usize> = (0..3).collect();
+    let mut cursor = list.cursor_front();
+
+    cursor.move_next();
+    cursor.insert_after(9);
+    assert_eq!(cursor.current(), Some(1));
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(9));
+
+    assert_eq!(list.size, 4);
+    assert_eq!(format!("{}", list), "0 -> 1 -> 9 -> 2 -> (sentinel)\n");
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![2, 9, 1, 0]);
+}
+
+#[test]
+fn test_cursor_remove_current() {
+    let mut list: List<usize> = (0..4).collect();
+    let mut cursor = list.cursor_front();
+
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), Some(1));
+    assert_eq!(cursor.current(), Some(2));
+
+    // Removing the last element leaves the cursor on the sentinel
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), Some(3));
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.remove_current(), None);
+
+    assert_eq!(list.size, 2);
+    assert_eq!(format!("{}", list), "0 -> 2 -> (sentinel)\n");
+    assert_eq!(list.back(), Some(2));
+}
+
+#[test]
+fn test_cursor_empty() {
+    let mut list: List<usize> = List::new_list();
+    let mut cursor = list.cursor_front();
+
+    assert_eq!(cursor.current(), None);
+    cursor.insert_after(5);
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(5));
+
+    assert_eq!(format!("{}", list), "5 -> (sentinel)\n");
+}
+
+#[test]
+fn test_split_at_middle() {
+    let list: List<usize> = (0..5).collect();
+
+    let (front, back) = list.split_at(2).unwrap();
+
+    assert_eq!(format!("{}", front), "0 -> 1 -> (sentinel)\n");
+    assert_eq!(format!("{}", back), "2 -> 3 -> 4 -> (sentinel)\n");
+    assert_eq!(format!("{}", list), "0 -> 1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+    assert_eq!(list.size, 5);
+}
+
+#[test]
+fn test_split_at_ends() {
+    let list: List<usize> = (0..5).collect();
+
+    let (front, back) = list.split_at(0).unwrap();
+    assert!(front.is_empty());
+    assert_eq!(back, list);
+
+    let (front, back) = list.split_at(5).unwrap();
+    assert_eq!(front, list);
+    assert!(back.is_empty());
+}
+
+#[test]
+fn test_split_at_invalid_index() {
+    let list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.split_at(6), Err(ListError::IndexOutOfBounds));
+}
+
+#[test]
+fn test_rotate_right() {
+    let mut list: List<usize> = (0..5).collect();
+
+    list.rotate_right(2);
+
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "3 -> 4 -> 0 -> 1 -> 2 -> (sentinel)\n");
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![2, 1, 0, 4, 3]);
+}
+
+#[test]
+fn test_rotate_right_by_size() {
+    let mut list: List<usize> = (0..5).collect();
+
+    list.rotate_right(5);
+    assert_eq!(list.to_vec(), vec![0, 1, 2, 3, 4]);
+
+    list.rotate_right(0);
+    assert_eq!(list.to_vec(), vec![0, 1, 2, 3, 4]);
+
+    list.rotate_right(7);
+    assert_eq!(list.to_vec(), vec![3, 4, 0, 1, 2]);
+
+    let mut empty: List<usize> = List::new_list();
+    empty.rotate_right(3);
+    assert_eq!(format!("{}", empty), "(sentinel)\n");
+}
+
+#[test]
+fn test_repeat() {
+    let list = List::repeat(7, 3);
+
+    assert_eq!(list.size, 3);
+    assert_eq!(format!("{}", list), "7 -> 7 -> 7 -> (sentinel)\n");
+
+    let empty: List<usize> = List::repeat(0, 0);
+    assert!(empty.is_empty());
+    assert_eq!(format!("{}", empty), "(sentinel)\n");
+}
+
+#[test]
+fn test_max_and_min() {
+    let list: List<usize> = List::from(vec![3, 7, 1, 9, 4]);
+
+    assert_eq!(List::max(&list), Some(9));
+    assert_eq!(List::min(&list), Some(1));
+    assert_eq!(list.size, 5);
+
+    let mut reversed = List::new_list_with_cmp(|a: &usize, b: &usize| b.cmp(a));
+    reversed.extend(vec![3, 7, 1, 9, 4]);
+    assert_eq!(List::max(&reversed), Some(1));
+    assert_eq!(List::min(&reversed), Some(9));
+}
+
+#[test]
+fn test_max_and_min_empty() {
+    let list: List<usize> = List::new_list();
+
+    assert_eq!(List::max(&list), None);
+    assert_eq!(List::min(&list), None);
+}
+
+#[test]
+fn test_sum_and_product() {
+    let list: List<usize> = (1..=4).collect();
+
+    assert_eq!(list.sum(), 10);
+    assert_eq!(list.product(), 24);
+
+    let empty: List<usize> = List::new_list();
+    assert_eq!(empty.sum(), 0);
+    assert_eq!(empty.product(), 1);
+}
+
+#[derive(PartialEq, Default, Clone, Debug)]
+struct NoDisplay {
+    id: usize,
+}
+
+#[test]
+fn test_list_without_display() {
+    let mut list: List<NoDisplay> = List::new_list();
+    for id in 0..3 {
+        list.add(NoDisplay { id });
+    }
+
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.remove_index(1), Ok(NoDisplay { id: 1 }));
+    assert_eq!(
+        list.to_vec(),
+        vec![NoDisplay { id: 2 }, NoDisplay { id: 0 }]
+    );
+    assert!(!list.is_empty());
+}
+
+#[derive(PartialEq, Clone, Debug)]
+struct NoDefault(&'static str);
+
+impl std::fmt::Display for NoDefault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[test]
+fn test_list_without_default() {
+    let mut list: List<NoDefault> = List::new_list();
+    list.add(NoDefault("b"));
+    list.add(NoDefault("a"));
+    list.push_back(NoDefault("c"));
+
+    assert!(list.sentinel.borrow().data.is_none());
+    assert_eq!(list.index_of(NoDefault("c")), Some(2));
+    assert_eq!(format!("{}", list), "a -> b -> c -> (sentinel)\n");
+
+    assert_eq!(list.remove_index(1), Ok(NoDefault("b")));
+    assert_eq!(list.pop_front(), Some(NoDefault("a")));
+    assert_eq!(format!("{}", list), "c -> (sentinel)\n");
+}
+
+#[test]
+fn test_add_front_slice() {
+    let mut list: List<usize> = List::with_elements(&[9]);
+
+    list.add_front_slice(&[1, 2, 3]);
+
+    assert_eq!(list.size, 4);
+    assert_eq!(format!("{}", list), "1 -> 2 -> 3 -> 9 -> (sentinel)\n");
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![9, 3, 2, 1]);
+
+    list.add_front_slice(&[]);
+    assert_eq!(list.size, 4);
+}
+
+#[test]
+fn test_eq_ignore_order() {
+    let list = List::from(vec![1, 2, 2, 3]);
+
+    assert!(list.eq_ignore_order(&List::from(vec![3, 2, 1, 2])));
+    assert!(!list.eq_ignore_order(&List::from(vec![1, 2, 3])));
+    assert!(!list.eq_ignore_order(&List::from(vec![1, 1, 2, 3])));
+    assert!(List::<usize>::new_list().eq_ignore_order(&List::new_list()));
+}
+
+#[test]
+fn test_chunks() {
+    let list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.chunks(2), vec![vec![0, 1], vec![2, 3], vec![4]]);
+    assert_eq!(list.chunks(5), vec![vec![0, 1, 2, 3, 4]]);
+    assert!(List::<usize>::new_list().chunks(3).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn test_chunks_zero() {
+    let list: List<usize> = (0..5).collect();
+
+    list.chunks(0);
+}
+
+#[test]
+fn test_windows() {
+    let list = List::from(vec![1, 2, 3, 4]);
+
+    assert_eq!(list.windows(2), vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+    assert_eq!(list.windows(4), vec![vec![1, 2, 3, 4]]);
+    assert!(list.windows(5).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn test_windows_zero() {
+    let list = List::from(vec![1, 2, 3, 4]);
+
+    list.windows(0);
+}
+
+#[test]
+fn test_nth_back() {
+    let list = List::from(vec![1, 2, 3]);
+
+    assert_eq!(list.nth_back(0), Some(3));
+    assert_eq!(list.nth_back(2), Some(1));
+    assert_eq!(list.nth_back(3), None);
+    assert_eq!(List::<usize>::new_list().nth_back(0), None);
+}
+
+#[test]
+fn test_concat() {
+    let lists = vec![
+        List::from(vec![1, 2]),
+        List::from(vec![3]),
+        List::new_list(),
+        List::from(vec![4, 5]),
+    ];
+
+    let list = List::concat(lists);
+
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "1 -> 2 -> 3 -> 4 -> 5 -> (sentinel)\n");
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![5, 4, 3, 2, 1]);
+    assert!(List::<usize>::concat(Vec::new()).is_empty());
+}
+
+#[allow(clippy::mutable_key_type)]
+#[test]
+fn test_lexicographic_ordering() {
+    let one_two = List::from(vec![1, 2]);
+    let one_three = List::from(vec![1, 3]);
+    let one = List::from(vec![1]);
+
+    assert!(one_two < one_three);
+    assert!(one < one_two);
+    assert!(List::new_list() < one);
+    assert_eq!(one_two.cmp(&List::from(vec![1, 2])), Ordering::Equal);
+
+    let mut lists = vec![one_three.clone(), one_two.clone(), one.clone()];
+    lists.sort();
+    assert_eq!(lists, vec![one.clone(), one_two.clone(), one_three.clone()]);
+
+    let set: BTreeSet<List<usize>> = [one_three, one.clone(), one_two, one].into_iter().collect();
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.first(), Some(&List::from(vec![1])));
+}
+
+#[test]
+fn test_partial_ordering() {
+    let list = List::from(vec![1.0, f64::NAN]);
+
+    assert_eq!(list.partial_cmp(&List::from(vec![1.0, 2.0])), None);
+    assert!(List::from(vec![0.5]) < list);
+}
+
+#[test]
+fn test_get_node_ref() {
+    let list: List<usize> = (0..5).collect();
+
+    let node = list.get_node_ref(3).unwrap();
+    assert_eq!(node.borrow().data, list.get(3));
+    assert!(Rc::ptr_eq(
+        &node.borrow().next.clone().unwrap(),
+        &list.get_node_ref(4).unwrap()
+    ));
+
+    assert!(list.get_node_ref(5).is_none());
+
+    // Writes through the handle are seen by the list
+    node.borrow_mut().data = Some(30);
+    assert_eq!(list.get(3), Some(30));
+    assert_eq!(list.to_vec(), vec![0, 1, 2, 30, 4]);
+    assert_eq!(list.len(), 5);
+}
+
+#[test]
+fn test_validate() {
+    let mut list: List<usize> = (0..5).collect();
+    assert_eq!(list.validate(), Ok(()));
+
+    list.remove_index(2).unwrap();
+    list.pop_back();
+    list.add(9);
+    assert_eq!(list.validate(), Ok(()));
+
+    list.clear();
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn test_validate_corrupted() {
+    let mut list: List<usize> = (0..5).collect();
+
+    list.size += 1;
+    assert_eq!(
+        list.validate(),
+        Err(ListError::Corrupt("there are fewer nodes than size says"))
+    );
+
+    list.size -= 2;
+    assert_eq!(
+        list.validate(),
+        Err(ListError::Corrupt("there are more nodes than size says"))
+    );
+    list.size += 1;
+
+    // Point the last node back at the first one instead of the fourth
+    let first = list.get_node_ref(0).unwrap();
+    let last = list.get_node_ref(4).unwrap();
+    let old_prev = last.borrow().prev.clone();
+    last.borrow_mut().prev = Some(Rc::downgrade(&first));
+    assert_eq!(
+        list.validate(),
+        Err(ListError::Corrupt(
+            "a prev link doesn't point at the node before it"
+        ))
+    );
+
+    last.borrow_mut().prev = old_prev;
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn test_remove_range() {
+    let mut list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.remove_range(1, 3), Ok(vec![1, 2]));
+    assert_eq!(list.size, 3);
+    assert_eq!(format!("{}", list), "0 -> 3 -> 4 -> (sentinel)\n");
+    assert_eq!(list.validate(), Ok(()));
+
+    assert_eq!(list.remove_range(1, 1), Ok(vec![]));
+    assert_eq!(list.remove_range(1, 3), Ok(vec![3, 4]));
+    assert_eq!(list.validate(), Ok(()));
+    assert_eq!(list.remove_range(0, 1), Ok(vec![0]));
+    assert!(list.is_empty());
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn test_remove_range_invalid() {
+    let mut list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.remove_range(3, 1), Err(ListError::InvalidRange));
+    assert_eq!(list.remove_range(2, 6), Err(ListError::IndexOutOfBounds));
+    assert_eq!(list.to_vec(), vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_split_when() {
+    let list = List::from(vec![1, 0, 2, 3, 0, 4]);
+
+    let pieces = list.split_when(|x| *x == 0);
+
+    assert_eq!(
+        pieces,
+        vec![
+            List::from(vec![1]),
+            List::from(vec![2, 3]),
+            List::from(vec![4])
+        ]
+    );
+    assert_eq!(list.size, 6);
+}
+
+#[test]
+fn test_split_when_edges() {
+    let list = List::from(vec![0, 1, 0, 0]);
+
+    let pieces: Vec<Vec<usize>> = list
+        .split_when(|x| *x == 0)
+        .iter()
+        .map(List::to_vec)
+        .collect();
+    assert_eq!(pieces, vec![vec![], vec![1], vec![], vec![]]);
+
+    let empty: List<usize> = List::new_list();
+    assert_eq!(empty.split_when(|x| *x == 0).len(), 1);
+}
+
+#[test]
+fn test_first_or_and_last_or() {
+    let list = List::from(vec![1, 2, 3]);
+    assert_eq!(list.first_or(42), 1);
+    assert_eq!(list.last_or(42), 3);
+
+    let empty: List<usize> = List::new_list();
+    assert_eq!(empty.first_or(42), 42);
+    assert_eq!(empty.last_or(7), 7);
+}
+
+#[test]
+fn test_take() {
+    let mut list = List::from(vec![1, 2, 3]);
+    let old_sentinel = Rc::clone(&list.sentinel);
+
+    let taken = list.take();
+
+    assert_eq!(format!("{}", taken), "1 -> 2 -> 3 -> (sentinel)\n");
+    assert!(Rc::ptr_eq(&taken.sentinel, &old_sentinel));
+    assert_eq!(taken.validate(), Ok(()));
+
+    assert!(list.is_empty());
+    assert_eq!(format!("{}", list), "(sentinel)\n");
+    assert_eq!(list.validate(), Ok(()));
+
+    list.add(4);
+    assert_eq!(list.to_vec(), vec![4]);
+    assert_eq!(taken.size, 3);
+}
+
+#[test]
+fn test_prepend() {
+    let mut list = List::from(vec![3, 4]);
+    let mut other = List::from(vec![1, 2]);
+
+    list.prepend(&mut other);
+
+    assert_eq!(list.size, 4);
+    assert_eq!(format!("{}", list), "1 -> 2 -> 3 -> 4 -> (sentinel)\n");
+    assert_eq!(list.iter_rev().collect::<Vec<usize>>(), vec![4, 3, 2, 1]);
+    assert_eq!(list.validate(), Ok(()));
+
+    assert!(other.is_empty());
+    assert_eq!(format!("{}", other), "(sentinel)\n");
+    assert_eq!(other.validate(), Ok(()));
+}
+
+#[test]
+fn test_prepend_empty() {
+    let mut list: List<usize> = List::new_list();
+    let mut other = List::from(vec![1, 2]);
+
+    list.prepend(&mut other);
+    assert_eq!(list.to_vec(), vec![1, 2]);
+    assert_eq!(list.validate(), Ok(()));
+
+    list.prepend(&mut other);
+    assert_eq!(list.to_vec(), vec![1, 2]);
+}
+
+#[test]
+fn test_binary_insert() {
+    let mut list = List::from(vec![1, 2, 4, 5]);
+
+    list.binary_insert(3);
+    assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5]);
+
+    list.binary_insert(0);
+    list.binary_insert(6);
+    assert_eq!(
+        format!("{}", list),
+        "0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> (sentinel)\n"
+    );
+    assert_eq!(list.size, 7);
+    assert_eq!(list.validate(), Ok(()));
+
+    let mut empty: List<usize> = List::new_list();
+    empty.binary_insert(1);
+    assert_eq!(empty.to_vec(), vec![1]);
+}
+
+#[test]
+fn test_binary_insert_with_cmp() {
+    let mut list = List::new_list_with_cmp(|a: &usize, b: &usize| b.cmp(a));
+    for data in [2, 5, 1, 4] {
+        list.binary_insert(data);
+    }
+
+    assert_eq!(list.to_vec(), vec![5, 4, 2, 1]);
+}
+
+#[test]
+fn test_dedup_by_key() {
+    let mut list: List<String> = ["a", "b", "cc", "dd", "e", "fff", "g"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+    list.dedup_by_key(|s| s.len());
+
+    assert_eq!(list.size, 5);
+    assert_eq!(
+        format!("{}", list),
+        "a -> cc -> e -> fff -> g -> (sentinel)\n"
+    );
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn test_swap_remove() {
+    let mut list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.swap_remove(1), Ok(1));
+    assert_eq!(list.size, 4);
+    assert_eq!(format!("{}", list), "0 -> 4 -> 2 -> 3 -> (sentinel)\n");
+
+    assert_eq!(list.swap_remove(3), Ok(3));
+    assert_eq!(list.to_vec(), vec![0, 4, 2]);
+    assert_eq!(list.validate(), Ok(()));
+
+    assert_eq!(list.swap_remove(3), Err(ListError::IndexOutOfBounds));
+    list.clear();
+    assert_eq!(list.swap_remove(0), Err(ListError::Empty));
+}
+
+#[test]
+fn test_get_or_insert() {
+    let mut list: List<usize> = List::new_list();
+
+    assert_eq!(list.get_or_insert(5), 0);
+    assert_eq!(list.get_or_insert(7), 1);
+    assert_eq!(list.size, 2);
+
+    assert_eq!(list.get_or_insert(5), 0);
+    assert_eq!(list.get_or_insert(7), 1);
+    assert_eq!(list.size, 2);
+    assert_eq!(format!("{}", list), "5 -> 7 -> (sentinel)\n");
+}
+
+#[test]
+fn test_iter_from() {
+    let list: List<usize> = (0..5).collect();
+
+    assert_eq!(list.iter_from(2).collect::<Vec<usize>>(), vec![2, 3, 4]);
+    assert_eq!(list.iter_from(0).collect::<Vec<usize>>(), list.to_vec());
+    assert_eq!(list.iter_from(4).collect::<Vec<usize>>(), vec![4]);
+    assert_eq!(list.iter_from(5).next(), None);
+    assert_eq!(list.iter_from(9).next(), None);
+}
+
+#[test]
+fn test_partition() {
+    let list: List<usize> = (0..6).collect();
+
+    let (even, odd) = list.partition(|x| x.is_multiple_of(2));
+
+    assert_eq!(format!("{}", even), "0 -> 2 -> 4 -> (sentinel)\n");
+    assert_eq!(format!("{}", odd), "1 -> 3 -> 5 -> (sentinel)\n");
+    assert_eq!(list.size, 6);
+}
+
+#[test]
+fn test_flatten() {
+    let nested: List<List<usize>> = List::from(vec![
+        List::from(vec![1, 2]),
+        List::new_list(),
+        List::from(vec![3]),
+    ]);
+
+    let flat = nested.flatten();
+
+    assert_eq!(flat.size, 3);
+    assert_eq!(format!("{}", flat), "1 -> 2 -> 3 -> (sentinel)\n");
+    assert_eq!(flat.validate(), Ok(()));
+}
+
+#[test]
+fn test_starts_with_and_ends_with() {
+    let list = List::from(vec![1, 2, 3, 4]);
+
+    assert!(list.starts_with(&[1, 2]));
+    assert!(!list.starts_with(&[2]));
+    assert!(list.starts_with(&[]));
+    assert!(!list.starts_with(&[1, 2, 3, 4, 5]));
+
+    assert!(list.ends_with(&[3, 4]));
+    assert!(!list.ends_with(&[3]));
+    assert!(list.ends_with(&[1, 2, 3, 4]));
+    assert!(!list.ends_with(&[0, 1, 2, 3, 4]));
+}
+
+#[test]
+fn test_replace_all() {
+    let mut list = List::from(vec![1, 2, 1, 3]);
+
+    assert_eq!(list.replace_all(&1, 9), 2);
+    assert_eq!(list.to_vec(), vec![9, 2, 9, 3]);
+    assert_eq!(list.size, 4);
+
+    assert_eq!(list.replace_all(&1, 9), 0);
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn test_into_vec() {
+    let list: List<usize> = (0..5).collect();
+    assert_eq!(list.into_vec(), vec![0, 1, 2, 3, 4]);
+
+    // Rc isn't cloned, so each one is uniquely owned once it's out of the list
+    let shared = List::from(vec![Rc::new(1), Rc::new(2)]);
+    let items = shared.into_vec();
+    assert!(items.iter().all(|item| Rc::strong_count(item) == 1));
+
+    let empty: List<usize> = List::new_list();
+    assert!(empty.into_vec().is_empty());
+}
+
+#[test]
+fn test_iter_indexed() {
+    let list = List::from(vec![10, 20, 30]);
+
+    assert_eq!(
+        list.iter_indexed().collect::<Vec<(usize, usize)>>(),
+        vec![(0, 10), (1, 20), (2, 30)]
+    );
+    assert_eq!(List::<usize>::new_list().iter_indexed().next(), None);
+}
+
+#[test]
+fn test_rotate_to_value() {
+    let mut list: List<usize> = (0..4).collect();
+
+    assert_eq!(list.rotate_to_value(&2), Ok(()));
+    assert_eq!(format!("{}", list), "2 -> 3 -> 0 -> 1 -> (sentinel)\n");
+    assert_eq!(list.validate(), Ok(()));
+
+    assert_eq!(list.rotate_to_value(&2), Ok(()));
+    assert_eq!(list.to_vec(), vec![2, 3, 0, 1]);
+
+    assert_eq!(list.rotate_to_value(&7), Err(ListError::NotFound));
+    assert_eq!(list.to_vec(), vec![2, 3, 0, 1]);
+}
+
+#[test]
+fn test_remove_indices() {
+    let mut list: List<char> = "abcde".chars().collect();
+
+    assert_eq!(list.remove_indices(&[0, 2, 4]), Ok(vec!['a', 'c', 'e']));
+    assert_eq!(list.size, 2);
+    assert_eq!(format!("{}", list), "b -> d -> (sentinel)\n");
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn test_remove_indices_keeps_given_order() {
+    let mut list: List<char> = "abcde".chars().collect();
+
+    assert_eq!(list.remove_indices(&[3, 0, 1]), Ok(vec!['d', 'a', 'b']));
+    assert_eq!(list.to_vec(), vec!['c', 'e']);
+    assert_eq!(list.remove_indices(&[]), Ok(vec![]));
+}
+
+#[test]
+fn test_remove_indices_invalid() {
+    let mut list: List<char> = "abcde".chars().collect();
+
+    assert_eq!(
+        list.remove_indices(&[1, 5]),
+        Err(ListError::IndexOutOfBounds)
+    );
+    assert_eq!(
+        list.remove_indices(&[2, 1, 2]),
+        Err(ListError::DuplicateIndex)
+    );
+    assert_eq!(list.size, 5);
+
+    list.clear();
+    assert_eq!(list.remove_indices(&[0]), Err(ListError::Empty));
+}
+
+#[test]
+fn test_join() {
+    let list = List::from(vec![4, 3, 2]);
+    assert_eq!(list.join(" | "), "4 | 3 | 2");
+
+    let empty: List<usize> = List::new_list();
+    assert_eq!(empty.join(" | "), "");
+}
+
+#[test]
+fn test_try_fold() {
+    let list = List::from(vec![1, 2, 3, 4]);
+
+    let sum: Result<usize, usize> = list.try_fold(0, |acc, x| Ok(acc + x));
+    assert_eq!(sum, Ok(10));
+
+    // The third element fails, so the fourth is never looked at
+    let mut seen = Vec::new();
+    let result = list.try_fold(0, |acc, x| {
+        seen.push(*x);
+        if *x == 3 {
+            Err(acc)
+        } else {
+            Ok(acc + x)
+        }
+    });
+    assert_eq!(result, Err(3));
+    assert_eq!(seen, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_count_if() {
+    let list: List<usize> = (0..10).collect();
+
+    assert_eq!(list.count_if(|x| x.is_multiple_of(2)), 5);
+    assert_eq!(list.count_if(|x| *x > 100), 0);
+}
+
+#[test]
+fn test_any_and_all() {
+    let list: List<i32> = List::from(vec![1, 5, 2, 4]);
+
+    assert!(list.any(|x| *x > 3));
+    assert!(!list.any(|x| *x > 5));
+    assert!(list.all(|x| *x >= 0));
+    assert!(!list.all(|x| *x > 1));
+
+    // Both stop as soon as the answer is known
+    let mut checked = 0;
+    assert!(list.any(|x| {
+        checked += 1;
+        *x == 5
+    }));
+    assert_eq!(checked, 2);
+
+    let empty: List<i32> = List::new_list();
+    assert!(!empty.any(|_| true));
+    assert!(empty.all(|_| false));
+}
+
+#[test]
+fn test_insert_sorted_unique() {
+    let mut list = List::from(vec![1, 3, 5]);
+
+    assert!(list.insert_sorted_unique(4));
+    assert_eq!(list.to_vec(), vec![1, 3, 4, 5]);
+
+    assert!(!list.insert_sorted_unique(3));
+    assert!(!list.insert_sorted_unique(1));
+    assert!(!list.insert_sorted_unique(5));
+    assert_eq!(list.size, 4);
+
+    assert!(list.insert_sorted_unique(0));
+    assert!(list.insert_sorted_unique(6));
+    assert_eq!(
+        format!("{}", list),
+        "0 -> 1 -> 3 -> 4 -> 5 -> 6 -> (sentinel)\n"
+    );
+
+    let mut empty: List<usize> = List::new_list();
+    assert!(empty.insert_sorted_unique(2));
+    assert_eq!(empty.to_vec(), vec![2]);
+}
+
+#[test]
+fn test_shift_left_and_right() {
+    let mut list = List::from(vec![1, 2, 3]);
+    let head = list.get_node_ref(0).unwrap();
+
+    list.shift_left();
+    assert_eq!(format!("{}", list), "2 -> 3 -> 1 -> (sentinel)\n");
+    assert!(Rc::ptr_eq(&head, &list.get_node_ref(0).unwrap()));
+    assert_eq!(head.borrow().data, Some(2));
+
+    list.shift_right();
+    list.shift_right();
+    assert_eq!(format!("{}", list), "3 -> 1 -> 2 -> (sentinel)\n");
+    assert_eq!(list.validate(), Ok(()));
+
+    let mut one = List::from(vec![7]);
+    one.shift_left();
+    one.shift_right();
+    assert_eq!(one.to_vec(), vec![7]);
+}
+
+#[test]
+fn test_group_consecutive() {
+    let list = List::from(vec![1, 1, 2, 3, 3, 3]);
+    assert_eq!(list.group_consecutive(), vec![(1, 2), (2, 1), (3, 3)]);
+
+    let list = List::from(vec![1, 2, 1]);
+    assert_eq!(list.group_consecutive(), vec![(1, 1), (2, 1), (1, 1)]);
+
+    assert!(List::<usize>::new_list().group_consecutive().is_empty());
+}
+
+#[test]
+fn test_from_iter_front_and_back() {
+    let front = List::from_iter_front(0..3);
+    assert_eq!(format!("{}", front), "2 -> 1 -> 0 -> (sentinel)\n");
+
+    let back = List::from_iter_back(0..3);
+    assert_eq!(format!("{}", back), "0 -> 1 -> 2 -> (sentinel)\n");
+    assert_eq!(back, (0..3).collect::<List<i32>>());
+}
+
+#[test]
+fn test_remove_if_first() {
+    let mut list = List::from(vec![1, 2, 3, 4]);
+
+    assert_eq!(list.remove_if_first(|x| *x > 2), Some(3));
+    assert_eq!(list.size, 3);
+    assert_eq!(format!("{}", list), "1 -> 2 -> 4 -> (sentinel)\n");
+
+    assert_eq!(list.remove_if_first(|x| *x > 10), None);
+    assert_eq!(list.size, 3);
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn test_get_signed() {
+    let list = List::from(vec![10, 20, 30]);
+
+    assert_eq!(list.get_signed(-1), Some(30));
+    assert_eq!(list.get_signed(-3), Some(10));
+    assert_eq!(list.get_signed(-4), None);
+    assert_eq!(list.get_signed(0), Some(10));
+    assert_eq!(list.get_signed(2), Some(30));
+    assert_eq!(list.get_signed(3), None);
+    assert_eq!(list.get_signed(isize::MIN), None);
+}
+
+#[test]
+fn test_interleave() {
+    let odd = List::from(vec![1, 3, 5]);
+    let even = List::from(vec![2, 4]);
+
+    let list = odd.interleave(&even);
+    assert_eq!(list.size, 5);
+    assert_eq!(format!("{}", list), "1 -> 2 -> 3 -> 4 -> 5 -> (sentinel)\n");
+
+    assert_eq!(even.interleave(&odd).to_vec(), vec![2, 1, 4, 3, 5]);
+    assert_eq!(odd.interleave(&List::new_list()), odd);
+    assert_eq!(odd.size, 3);
+}
+
+#[test]
+fn test_zip() {
+    let numbers = List::from(vec![1, 2, 3]);
+    let letters = List::from(vec!["a", "b"]);
+
+    let pairs = numbers.zip(&letters);
+
+    assert_eq!(pairs.size, 2);
+    assert_eq!(pairs.to_vec(), vec![(1, "a"), (2, "b")]);
+    assert_eq!(letters.zip(&numbers).to_vec(), vec![("a", 1), ("b", 2)]);
+    assert!(numbers.zip(&List::<usize>::new_list()).is_empty());
+}
+
+#[test]
+fn test_merge_sorted() {
+    let mut list = List::from(vec![1, 3, 5]);
+    let other = List::from(vec![2, 4, 6]);
+    let moved = other.get_node_ref(0).unwrap();
+
+    list.merge_sorted(other);
+
+    assert_eq!(list.size, 6);
+    assert_eq!(
+        format!("{}", list),
+        "1 -> 2 -> 3 -> 4 -> 5 -> 6 -> (sentinel)\n"
+    );
+    assert_eq!(
+        list.iter_rev().collect::<Vec<usize>>(),
+        vec![6, 5, 4, 3, 2, 1]
+    );
+    assert!(Rc::ptr_eq(&moved, &list.get_node_ref(1).unwrap()));
+    assert_eq!(list.validate(), Ok(()));
+}
+
+#[test]
+fn test_merge_sorted_uneven() {
+    let mut list = List::from(vec![2, 2, 7]);
+    list.merge_sorted(List::from(vec![0, 2, 8, 9]));
+    assert_eq!(list.to_vec(), vec![0, 2, 2, 2, 7, 8, 9]);
+    assert_eq!(list.validate(), Ok(()));
+
+    list.merge_sorted(List::new_list());
+    assert_eq!(list.size, 7);
+
+    let mut empty: List<usize> = List::new_list();
+    empty.merge_sorted(List::from(vec![1, 2]));
+    assert_eq!(empty.to_vec(), vec![1, 2]);
+    assert_eq!(empty.validate(), Ok(()));
+}
+
+#[test]
+fn test_eq_ignore_order_with_cmp() {
+    let mut list: List<String> =
+        List::new_list_with_cmp(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+    list.extend(["a", "B", "b"].iter().map(|s| s.to_string()));
+    let other: List<String> = ["b", "A", "b"].iter().map(|s| s.to_string()).collect();
+
+    assert!(list.eq_ignore_order(&other));
+    assert!(!other.eq_ignore_order(&list));
+}
+
+#[test]
+fn test_replace_all_with_cmp() {
+    let mut list: List<String> =
+        List::new_list_with_cmp(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+    list.extend(["a", "B", "A"].iter().map(|s| s.to_string()));
+
+    assert_eq!(list.replace_all(&String::from("A"), String::from("z")), 2);
+    assert_eq!(format!("{}", list), "z -> B -> z -> (sentinel)\n");
+}
+
+#[test]
+fn test_starts_with_and_ends_with_cmp() {
+    let mut list: List<String> =
+        List::new_list_with_cmp(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+    list.extend(["a", "B", "c"].iter().map(|s| s.to_string()));
+
+    assert!(list.starts_with(&[String::from("A"), String::from("b")]));
+    assert!(list.ends_with(&[String::from("C")]));
+}
+
+#[test]
+fn test_group_consecutive_with_cmp() {
+    let mut list: List<String> =
+        List::new_list_with_cmp(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+    list.extend(["a", "A", "b"].iter().map(|s| s.to_string()));
+
+    assert_eq!(
+        list.group_consecutive(),
+        vec![(String::from("a"), 2), (String::from("b"), 1)]
+    );
+}
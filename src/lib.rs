@@ -0,0 +1,20 @@
+// The list only needs an allocator, so std is optional. Tests always get std.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(feature = "alloc"))]
+compile_error!("the `alloc` feature is required, the list can't be built without an allocator");
+
+extern crate alloc;
+
+// Mutex and the atomics it relies on to be shared between threads live in std
+#[cfg(feature = "std")]
+pub mod concurrent;
+pub mod lab;
+
+#[cfg(test)]
+#[path = "./lab_test.rs"]
+pub mod lab_test;
+
+#[cfg(all(test, feature = "std"))]
+#[path = "./concurrent_test.rs"]
+pub mod concurrent_test;